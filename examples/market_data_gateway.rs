@@ -10,23 +10,44 @@
 //! Based on Artio's market data gateway patterns.
 
 use async_trait::async_trait;
-use fixg::messages::AdminMessage;
-use fixg::session::SessionConfig;
+use fixg::messages::{
+    AdminMessage, AppMessage, MDEntry, MDEntryType, MDUpdateAction, MarketDataIncrementalRefresh,
+    MarketDataRequest, MarketDataSnapshotFullRefresh, SubscriptionRequestType,
+};
+use fixg::session::{BackpressurePolicy, SessionConfig};
 use fixg::{
     FixClient, FixClientConfig, FixHandler, Gateway, GatewayConfig, InboundMessage, Session,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{broadcast, Mutex};
-use tokio::time::{self, Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::{self, Duration};
+
+/// Per-session state for a symbol a client has subscribed to via
+/// `SubscriptionRequestType::SnapshotPlusUpdates`.
+#[derive(Debug, Clone)]
+struct SubscriptionState {
+    /// MDReqID(262) the subscription was opened under, echoed back on every
+    /// incremental update so the client can correlate it.
+    md_req_id: String,
+}
 
-/// Market data distributor that manages client subscriptions
+/// Market data distributor that manages per-symbol, per-client subscriptions
+/// driven by FIX MarketDataRequest(35=V), answering with a
+/// MarketDataSnapshotFullRefresh(35=W) and, for snapshot-plus-updates
+/// subscribers, pushing MarketDataIncrementalRefresh(35=X) deltas — rather
+/// than blindly broadcasting every tick to every connected session.
 #[derive(Clone)]
 struct MarketDataDistributor {
-    /// Broadcast channel for market data
-    market_data_tx: broadcast::Sender<MarketData>,
     /// Active client sessions
     clients: Arc<Mutex<HashMap<String, Arc<Session>>>>,
+    /// symbol -> session_id -> subscription state
+    subscriptions: Arc<Mutex<HashMap<String, HashMap<String, SubscriptionState>>>>,
+    /// Last known tick per symbol, used to answer snapshot requests. This
+    /// feed only ever tracks one price per symbol (not a real multi-level
+    /// book), so `MarketDepth`(264) on a request is accepted but has
+    /// nothing deeper than depth 1 to limit.
+    latest: Arc<Mutex<HashMap<String, MDEntry>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,15 +55,14 @@ struct MarketData {
     symbol: String,
     price: f64,
     quantity: u64,
-    timestamp: Instant,
 }
 
 impl MarketDataDistributor {
     fn new() -> Self {
-        let (market_data_tx, _) = broadcast::channel(10000);
         Self {
-            market_data_tx,
             clients: Arc::new(Mutex::new(HashMap::new())),
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            latest: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -55,30 +75,98 @@ impl MarketDataDistributor {
     async fn remove_client(&self, session_id: &str) {
         let mut clients = self.clients.lock().await;
         clients.remove(session_id);
+
+        let mut subscriptions = self.subscriptions.lock().await;
+        for sessions in subscriptions.values_mut() {
+            sessions.remove(session_id);
+        }
         println!("Removed client: {}", session_id);
     }
 
-    async fn publish_market_data(&self, data: MarketData) {
-        // Send via broadcast channel (for demonstration)
-        if let Err(_) = self.market_data_tx.send(data.clone()) {
-            println!("No active subscribers for market data");
-            return;
+    /// Handles an inbound MarketDataRequest(35=V). An unsubscribe drops the
+    /// session's subscription to each requested symbol and sends nothing;
+    /// any other request sends a snapshot of every requested symbol and,
+    /// for snapshot-plus-updates, keeps the session registered for
+    /// incremental updates.
+    async fn handle_subscription(&self, session_id: &str, session: &Session, req: &MarketDataRequest) {
+        for symbol in req.symbols() {
+            if req.subscription_request_type() == SubscriptionRequestType::Unsubscribe {
+                if let Some(sessions) = self.subscriptions.lock().await.get_mut(symbol) {
+                    sessions.remove(session_id);
+                }
+                continue;
+            }
+
+            let current = self.latest.lock().await.get(symbol).copied();
+            let mut builder = MarketDataSnapshotFullRefresh::builder()
+                .md_req_id(req.md_req_id())
+                .symbol(symbol.clone());
+            if let Some(entry) = current {
+                builder = builder.entry(entry);
+            }
+            if let Err(e) = session.send_raw(&builder.build().encode()).await {
+                println!("Failed to send snapshot to {}: {}", session_id, e);
+            }
+
+            if req.subscription_request_type() == SubscriptionRequestType::SnapshotPlusUpdates {
+                self.subscriptions
+                    .lock()
+                    .await
+                    .entry(symbol.clone())
+                    .or_default()
+                    .insert(
+                        session_id.to_string(),
+                        SubscriptionState { md_req_id: req.md_req_id().to_string() },
+                    );
+            }
         }
+    }
+
+    async fn publish_market_data(&self, data: MarketData) {
+        let entry = MDEntry {
+            entry_type: MDEntryType::Bid,
+            px: data.price,
+            size: data.quantity as i64,
+        };
+        self.latest.lock().await.insert(data.symbol.clone(), entry);
+
+        let subscribers = match self.subscriptions.lock().await.get(&data.symbol) {
+            Some(sessions) if !sessions.is_empty() => sessions.clone(),
+            _ => return,
+        };
 
-        // Also send directly to FIX clients
         let clients = self.clients.lock().await;
-        for (client_id, session) in clients.iter() {
-            let fix_message = format!(
-                "35=D|55={}|44={}|38={}|52={}",
-                data.symbol,
-                data.price,
-                data.quantity,
-                data.timestamp.elapsed().as_millis()
-            );
+        for (session_id, state) in &subscribers {
+            let Some(session) = clients.get(session_id) else { continue };
+
+            let incremental = MarketDataIncrementalRefresh::builder()
+                .md_req_id(state.md_req_id.clone())
+                .symbol(data.symbol.clone())
+                .update_action(MDUpdateAction::Change)
+                .entry(entry)
+                .build();
+
+            // Keyed by symbol so a lagging client's configured backpressure
+            // policy (e.g. Conflate) can overwrite a still-queued stale tick
+            // for this symbol with this newer one, rather than piling up an
+            // unbounded backlog behind a slow reader at 100Hz.
+            if let Err(e) = session.send_keyed(data.symbol.clone(), incremental.encode()).await {
+                println!("Failed to send to client {}: {}", session_id, e);
+            }
+        }
+    }
 
-            // Send as raw FIX message (in real implementation, use generated types)
-            if let Err(e) = session.send_raw(fix_message.as_bytes()).await {
-                println!("Failed to send to client {}: {}", client_id, e);
+    /// Logs each client's outbound backpressure counters, so operators can
+    /// see which clients are falling behind.
+    async fn log_session_metrics(&self) {
+        let clients = self.clients.lock().await;
+        for (session_id, session) in clients.iter() {
+            let metrics = session.metrics();
+            if metrics.dropped > 0 || metrics.conflated > 0 || metrics.queue_depth > 0 {
+                println!(
+                    "Client {} backpressure: dropped={} conflated={} queue_depth={}",
+                    session_id, metrics.dropped, metrics.conflated, metrics.queue_depth
+                );
             }
         }
     }
@@ -92,37 +180,44 @@ impl MarketDataDistributor {
 
             loop {
                 interval.tick().await;
-                
+
                 for symbol in &symbols {
                     let data = MarketData {
                         symbol: symbol.to_string(),
                         price: 1.0 + (counter as f64 * 0.0001) % 0.1,
                         quantity: 1000000 + (counter % 1000),
-                        timestamp: Instant::now(),
                     };
-                    
+
                     distributor.publish_market_data(data).await;
                     counter += 1;
                 }
             }
         });
     }
+
+    fn start_metrics_reporter(&self) {
+        let distributor = self.clone();
+        tokio::spawn(async move {
+            let mut interval = time::interval(Duration::from_secs(5));
+            loop {
+                interval.tick().await;
+                distributor.log_session_metrics().await;
+            }
+        });
+    }
 }
 
 /// FIX handler for market data clients
 struct MarketDataHandler {
     distributor: MarketDataDistributor,
     session_id: String,
-    market_data_rx: Option<broadcast::Receiver<MarketData>>,
 }
 
 impl MarketDataHandler {
     fn new(distributor: MarketDataDistributor, session_id: String) -> Self {
-        let market_data_rx = Some(distributor.market_data_tx.subscribe());
         Self {
             distributor,
             session_id,
-            market_data_rx,
         }
     }
 }
@@ -131,7 +226,7 @@ impl MarketDataHandler {
 impl FixHandler for MarketDataHandler {
     async fn on_session_active(&mut self, session: &Session) {
         println!("Market data session active: {}", self.session_id);
-        
+
         // Register this client with the distributor
         self.distributor
             .add_client(self.session_id.clone(), Arc::new(session.clone()))
@@ -143,20 +238,6 @@ impl FixHandler for MarketDataHandler {
                 id: "INITIAL".to_string(),
             })
             .await;
-
-        // Start listening for market data broadcasts
-        if let Some(mut rx) = self.market_data_rx.take() {
-            let session = session.clone();
-            let session_id = self.session_id.clone();
-            
-            tokio::spawn(async move {
-                while let Ok(data) = rx.recv().await {
-                    // Process market data and potentially send to client
-                    // This is just for demonstration - real implementation would
-                    // handle subscriptions, filtering, etc.
-                }
-            });
-        }
     }
 
     async fn on_message(&mut self, session: &Session, msg: InboundMessage) {
@@ -178,17 +259,21 @@ impl FixHandler for MarketDataHandler {
                     println!("Received admin message from {}: {:?}", self.session_id, admin);
                 }
             }
+        } else if let Some(req) = msg.as_app::<MarketDataRequest>() {
+            println!(
+                "Processing MarketDataRequest from {} ({} symbols)",
+                self.session_id,
+                req.symbols().len()
+            );
+            self.distributor
+                .handle_subscription(&self.session_id, session, &req)
+                .await;
         } else {
-            // Handle application messages (market data subscriptions, etc.)
             println!(
-                "Received application message from {}: {} bytes",
+                "Received unrecognized application message from {}: {} bytes",
                 self.session_id,
                 msg.body().len()
             );
-            
-            // Parse and handle subscription requests
-            // In a real implementation, this would parse FIX messages
-            // and manage client subscriptions
         }
     }
 
@@ -207,6 +292,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Start market data feed
     distributor.start_market_data_feed().await;
+    distributor.start_metrics_reporter();
 
     // Configure gateway to accept connections
     let gateway_config = GatewayConfig {
@@ -239,6 +325,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .sender_comp_id(&session_id)
                 .target_comp_id("MDGATEWAY")
                 .heartbeat_interval_secs(30)
+                .backpressure_policy(BackpressurePolicy::Conflate)
                 .build()?;
 
             let _session = client.initiate(session_config).await?;
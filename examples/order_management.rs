@@ -10,7 +10,11 @@
 //! - State persistence
 
 use async_trait::async_trait;
-use fixg::messages::{AdminMessage, ExecutionReport, ExecType, OrdStatus};
+use fixg::matching::{IncomingOrder, OrderBook};
+use fixg::messages::{
+    AdminMessage, AppMessage, ExecType, ExecutionReport, OrdStatus, OrdType, OrderCancelRequest,
+    OrderRequest, Side,
+};
 use fixg::session::SessionConfig;
 use fixg::{
     FixClient, FixClientConfig, FixHandler, Gateway, GatewayConfig, InboundMessage, Session,
@@ -20,23 +24,90 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+/// Identifier for a reserved-but-not-yet-confirmed match.
+type MatchId = String;
+
+/// A match the book has produced but execution hasn't confirmed yet. Reserved
+/// out of the order's `leaves_qty` into `pending_qty` when recorded; a later
+/// [`OrderManager::confirm_match`] releases the reservation with
+/// `Order::release_pending` and records a [`Trade`], or
+/// [`OrderManager::rollback_match`] undoes the reservation with
+/// `Order::rollback_pending` if execution fails or times out.
+#[derive(Debug, Clone)]
+struct ExecutableMatch {
+    cl_ord_id: String,
+    match_id: MatchId,
+    qty: i64,
+    px: f64,
+}
+
+/// A single confirmed trade that contributed to an order's fill. Unlike the
+/// `cum_qty`/`avg_px` running counters on `Order`, this is an append-only
+/// record: `OrderManager` derives `cum_qty`/`avg_px` by summing the trades
+/// recorded for an order rather than incrementing them in place, so a
+/// downstream consumer can independently reconcile an order's filled amount
+/// from the trade list, and a replayed or out-of-order trade still sums to
+/// the right totals.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Trade {
+    pub trade_id: String,
+    pub order_id: String,
+    pub cl_ord_id: String,
+    pub qty: i64,
+    pub px: f64,
+    pub exec_id: String,
+    /// Milliseconds since the Unix epoch.
+    pub ts: u64,
+}
+
+/// Point-in-time snapshot of an order's state, as returned by
+/// [`OrderManager::order_state`].
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct OrderSnapshot {
+    pub cl_ord_id: String,
+    pub order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub ord_status: OrdStatus,
+    pub order_qty: u64,
+    pub cum_qty: u64,
+    pub leaves_qty: u64,
+    pub avg_px: f64,
+}
+
+fn now_millis() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
 #[derive(Debug, Clone)]
 struct Order {
     cl_ord_id: String,
     order_id: String,
     symbol: String,
-    side: char, // '1' = Buy, '2' = Sell
+    side: Side,
     order_qty: u64,
     price: Option<f64>,
-    ord_type: char, // '1' = Market, '2' = Limit
+    ord_type: OrdType,
     ord_status: OrdStatus,
+    /// Derived by `OrderManager::recompute_from_trades` by summing this
+    /// order's recorded trades, not incremented in place.
     cum_qty: u64,
     leaves_qty: u64,
+    /// Quantity reserved by matches that have been recorded but not yet
+    /// confirmed or rolled back. Already excluded from `leaves_qty`.
+    pending_qty: u64,
+    /// Derived alongside `cum_qty`, see above.
     avg_px: f64,
 }
 
 impl Order {
-    fn new(cl_ord_id: String, symbol: String, side: char, order_qty: u64, price: Option<f64>, ord_type: char) -> Self {
+    fn new(cl_ord_id: String, symbol: String, side: Side, order_qty: u64, price: Option<f64>, ord_type: OrdType) -> Self {
         Self {
             cl_ord_id,
             order_id: Uuid::new_v4().to_string(),
@@ -48,26 +119,44 @@ impl Order {
             ord_status: OrdStatus::New,
             cum_qty: 0,
             leaves_qty: order_qty,
+            pending_qty: 0,
             avg_px: 0.0,
         }
     }
 
-    fn fill(&mut self, fill_qty: u64, fill_price: f64) {
-        let old_cum_qty = self.cum_qty;
-        self.cum_qty += fill_qty;
-        self.leaves_qty = self.order_qty.saturating_sub(self.cum_qty);
+    /// Excludes both confirmed fills and quantity reserved by pending
+    /// matches, so `leaves_qty` always reflects what's actually available to
+    /// match or cancel.
+    fn recompute_leaves_qty(&mut self) {
+        self.leaves_qty = self.order_qty.saturating_sub(self.cum_qty).saturating_sub(self.pending_qty);
+    }
 
-        // Update average price
-        if self.cum_qty > 0 {
-            self.avg_px = ((self.avg_px * old_cum_qty as f64) + (fill_price * fill_qty as f64)) / self.cum_qty as f64;
-        }
+    /// Reserves `qty` out of `leaves_qty` into `pending_qty` for a match that
+    /// has been recorded but not yet confirmed.
+    fn reserve_pending(&mut self, qty: u64) {
+        self.pending_qty += qty;
+        self.recompute_leaves_qty();
+    }
 
-        // Update status
-        if self.leaves_qty == 0 {
-            self.ord_status = OrdStatus::Filled;
+    /// Releases `qty` from `pending_qty` once its match has been confirmed
+    /// and recorded as a `Trade`. Does not touch `cum_qty`/`avg_px`/
+    /// `ord_status` — `OrderManager::recompute_from_trades` derives those
+    /// from the trade ledger right after this is called.
+    fn release_pending(&mut self, qty: u64) {
+        self.pending_qty = self.pending_qty.saturating_sub(qty);
+    }
+
+    /// Undoes a previously reserved match: releases it from `pending_qty`
+    /// without recording a trade, and reverts `ord_status` to what it would
+    /// be with only confirmed fills applied (`New` or `PartiallyFilled`).
+    fn rollback_pending(&mut self, qty: u64) {
+        self.pending_qty = self.pending_qty.saturating_sub(qty);
+        self.recompute_leaves_qty();
+        self.ord_status = if self.cum_qty == 0 {
+            OrdStatus::New
         } else {
-            self.ord_status = OrdStatus::PartiallyFilled;
-        }
+            OrdStatus::PartiallyFilled
+        };
     }
 }
 
@@ -75,14 +164,29 @@ impl Order {
 #[derive(Clone)]
 struct OrderManager {
     orders: Arc<Mutex<HashMap<String, Order>>>,
+    /// One price-time-priority book per symbol, crossed on every new order.
+    books: Arc<Mutex<HashMap<String, OrderBook>>>,
+    /// Matches the book has produced but execution hasn't confirmed yet,
+    /// keyed by match ID so a crash-recovery path can re-resolve them
+    /// deterministically (by confirming or rolling each back) rather than
+    /// losing track of in-flight matches.
+    pending_matches: Arc<Mutex<HashMap<MatchId, ExecutableMatch>>>,
+    /// Confirmed trades per order, keyed by `cl_ord_id`, in execution order.
+    /// Source of truth for `cum_qty`/`avg_px` — see `recompute_from_trades`.
+    trades: Arc<Mutex<HashMap<String, Vec<Trade>>>>,
     session: Option<Arc<Session>>,
+    comp_id: String,
 }
 
 impl OrderManager {
-    fn new() -> Self {
+    fn new(comp_id: String) -> Self {
         Self {
             orders: Arc::new(Mutex::new(HashMap::new())),
+            books: Arc::new(Mutex::new(HashMap::new())),
+            pending_matches: Arc::new(Mutex::new(HashMap::new())),
+            trades: Arc::new(Mutex::new(HashMap::new())),
             session: None,
+            comp_id,
         }
     }
 
@@ -90,21 +194,20 @@ impl OrderManager {
         self.session = Some(session);
     }
 
-    async fn handle_new_order(&self, cl_ord_id: String, symbol: String, side: char, qty: u64, price: Option<f64>, ord_type: char) -> Result<(), String> {
+    async fn handle_new_order(&self, cl_ord_id: String, symbol: String, side: Side, qty: u64, price: Option<f64>, ord_type: OrdType) -> Result<(), String> {
         // Risk checks
         if qty == 0 {
             return Err("Invalid quantity".to_string());
         }
 
-        if ord_type == '2' && price.is_none() {
+        if ord_type == OrdType::Limit && price.is_none() {
             return Err("Limit orders require a price".to_string());
         }
 
         // Create order
-        let mut order = Order::new(cl_ord_id.clone(), symbol, side, qty, price, ord_type);
+        let mut order = Order::new(cl_ord_id.clone(), symbol.clone(), side, qty, price, ord_type);
         order.ord_status = OrdStatus::New;
 
-        // Store order
         {
             let mut orders = self.orders.lock().await;
             orders.insert(cl_ord_id.clone(), order.clone());
@@ -113,91 +216,229 @@ impl OrderManager {
         // Send acknowledgment
         self.send_execution_report(&order, ExecType::New).await?;
 
-        // For demonstration, immediately start working the order
-        self.start_order_execution(cl_ord_id).await;
+        // Cross the incoming order against the book and report every fill it
+        // and any resting counterparty order produced.
+        let match_result = {
+            let mut books = self.books.lock().await;
+            books.entry(symbol).or_default().submit(IncomingOrder {
+                cl_ord_id: cl_ord_id.clone(),
+                comp_id: self.comp_id.clone(),
+                side,
+                ord_type,
+                price: price.unwrap_or(0.0),
+                qty: qty as i64,
+            })
+        };
+
+        for fill in &match_result.fills {
+            // Real execution can fail or never complete, so a match is
+            // reserved and reported as Pending rather than committed
+            // outright; a separate confirm step (here, immediate, since this
+            // in-process book is authoritative) commits it or rolls it back.
+            let incoming_match_id = self.reserve_match(&fill.incoming_cl_ord_id, fill.qty, fill.price).await;
+            let resting_match_id = self.reserve_match(&fill.resting_cl_ord_id, fill.qty, fill.price).await;
+
+            if let Some(match_id) = incoming_match_id {
+                self.confirm_match(&match_id).await;
+            }
+            if let Some(match_id) = resting_match_id {
+                self.confirm_match(&match_id).await;
+            }
+        }
+
+        for canceled in &match_result.self_trade_cancels {
+            self.apply_cancel(&canceled.cl_ord_id).await;
+        }
+
+        if match_result.canceled_leaves_qty > 0 {
+            // Market order residual that couldn't be filled: done, canceled.
+            self.apply_cancel(&cl_ord_id).await;
+        }
 
         Ok(())
     }
 
-    async fn start_order_execution(&self, cl_ord_id: String) {
-        let order_manager = self.clone();
-        
-        tokio::spawn(async move {
-            // Simulate order execution delay
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-
-            let mut orders = order_manager.orders.lock().await;
-            if let Some(order) = orders.get_mut(&cl_ord_id) {
-                if order.ord_status == OrdStatus::New {
-                    // Simulate partial fill
-                    let fill_qty = std::cmp::min(order.leaves_qty, order.order_qty / 3);
-                    let fill_price = order.price.unwrap_or(100.0); // Use order price or market price
-
-                    order.fill(fill_qty, fill_price);
-                    let updated_order = order.clone();
-                    drop(orders); // Release lock before async call
-
-                    // Send execution report
-                    let _ = order_manager.send_execution_report(&updated_order, ExecType::PartialFill).await;
-
-                    // If there are leaves, schedule another fill
-                    if updated_order.leaves_qty > 0 {
-                        let order_manager_clone = order_manager.clone();
-                        tokio::spawn(async move {
-                            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-                            order_manager_clone.complete_order_execution(cl_ord_id).await;
-                        });
-                    }
-                }
-            }
-        });
+    /// Records a match produced by the matching engine as pending: reserves
+    /// `qty` out of the order's `leaves_qty` and reports `ExecType::PendingNew`
+    /// (no prior fills) or `ExecType::PendingFill` (order already has at
+    /// least one confirmed fill), rather than committing immediately.
+    /// Returns the match ID to later pass to [`Self::confirm_match`] or
+    /// [`Self::rollback_match`], or `None` if this manager isn't tracking
+    /// `cl_ord_id` (the resting side of a self-crossed demo order is always
+    /// one of ours, but a future multi-party gateway might route elsewhere).
+    async fn reserve_match(&self, cl_ord_id: &str, qty: i64, price: f64) -> Option<MatchId> {
+        let (updated_order, exec_type) = {
+            let mut orders = self.orders.lock().await;
+            let order = orders.get_mut(cl_ord_id)?;
+            let exec_type = if order.cum_qty == 0 {
+                ExecType::PendingNew
+            } else {
+                ExecType::PendingFill
+            };
+            order.reserve_pending(qty as u64);
+            (order.clone(), exec_type)
+        };
+
+        let match_id = Uuid::new_v4().to_string();
+        self.pending_matches.lock().await.insert(
+            match_id.clone(),
+            ExecutableMatch {
+                cl_ord_id: cl_ord_id.to_string(),
+                match_id: match_id.clone(),
+                qty,
+                px: price,
+            },
+        );
+
+        let _ = self.send_execution_report(&updated_order, exec_type).await;
+        Some(match_id)
+    }
+
+    /// Commits a previously reserved match: applies the fill and reports
+    /// `ExecType::Fill`/`ExecType::PartialFill`. No-op if `match_id` isn't
+    /// (or is no longer) pending.
+    async fn confirm_match(&self, match_id: &MatchId) {
+        let Some(pending) = self.pending_matches.lock().await.remove(match_id) else { return };
+
+        let order_id = {
+            let mut orders = self.orders.lock().await;
+            let Some(order) = orders.get_mut(&pending.cl_ord_id) else { return };
+            order.release_pending(pending.qty as u64);
+            order.order_id.clone()
+        };
+
+        self.trades
+            .lock()
+            .await
+            .entry(pending.cl_ord_id.clone())
+            .or_default()
+            .push(Trade {
+                trade_id: Uuid::new_v4().to_string(),
+                order_id,
+                cl_ord_id: pending.cl_ord_id.clone(),
+                qty: pending.qty,
+                px: pending.px,
+                exec_id: Uuid::new_v4().to_string(),
+                ts: now_millis(),
+            });
+        self.recompute_from_trades(&pending.cl_ord_id).await;
+
+        let (updated_order, exec_type) = {
+            let orders = self.orders.lock().await;
+            let Some(order) = orders.get(&pending.cl_ord_id) else { return };
+            let exec_type = if order.leaves_qty == 0 && order.pending_qty == 0 {
+                ExecType::Fill
+            } else {
+                ExecType::PartialFill
+            };
+            (order.clone(), exec_type)
+        };
+
+        let _ = self.send_execution_report(&updated_order, exec_type).await;
     }
 
-    async fn complete_order_execution(&self, cl_ord_id: String) {
+    /// Recomputes `cum_qty`, `avg_px`, `leaves_qty`, and `ord_status` on the
+    /// order for `cl_ord_id` by summing its recorded trades, rather than
+    /// incrementing them in place — so the order's totals always reconcile
+    /// with the trade ledger regardless of the order matches were recorded
+    /// or replayed in.
+    async fn recompute_from_trades(&self, cl_ord_id: &str) {
+        let trades = self.trades_for(cl_ord_id).await;
         let mut orders = self.orders.lock().await;
-        if let Some(order) = orders.get_mut(&cl_ord_id) {
-            if order.leaves_qty > 0 {
-                let fill_price = order.price.unwrap_or(100.0);
-                order.fill(order.leaves_qty, fill_price);
-                let updated_order = order.clone();
-                drop(orders);
+        let Some(order) = orders.get_mut(cl_ord_id) else { return };
+
+        let cum_qty: i64 = trades.iter().map(|t| t.qty).sum();
+        let notional: f64 = trades.iter().map(|t| t.qty as f64 * t.px).sum();
+
+        order.cum_qty = cum_qty as u64;
+        order.avg_px = if cum_qty > 0 { notional / cum_qty as f64 } else { 0.0 };
+        order.recompute_leaves_qty();
+        order.ord_status = if order.leaves_qty == 0 && order.pending_qty == 0 {
+            OrdStatus::Filled
+        } else if order.cum_qty > 0 {
+            OrdStatus::PartiallyFilled
+        } else {
+            OrdStatus::New
+        };
+    }
 
-                let _ = self.send_execution_report(&updated_order, ExecType::Fill).await;
-            }
-        }
+    /// Returns all confirmed trades recorded for `cl_ord_id`, in execution
+    /// order, so a downstream consumer can independently sum quantities to
+    /// confirm the order's filled amount.
+    #[allow(dead_code)]
+    async fn trades_for(&self, cl_ord_id: &str) -> Vec<Trade> {
+        self.trades.lock().await.get(cl_ord_id).cloned().unwrap_or_default()
+    }
+
+    /// Returns a point-in-time snapshot of the order tracked under
+    /// `cl_ord_id`, or `None` if this manager isn't tracking it.
+    #[allow(dead_code)]
+    async fn order_state(&self, cl_ord_id: &str) -> Option<OrderSnapshot> {
+        let orders = self.orders.lock().await;
+        let order = orders.get(cl_ord_id)?;
+        Some(OrderSnapshot {
+            cl_ord_id: order.cl_ord_id.clone(),
+            order_id: order.order_id.clone(),
+            symbol: order.symbol.clone(),
+            side: order.side,
+            ord_status: order.ord_status,
+            order_qty: order.order_qty,
+            cum_qty: order.cum_qty,
+            leaves_qty: order.leaves_qty,
+            avg_px: order.avg_px,
+        })
+    }
+
+    /// Rolls back a previously reserved match on execution failure or
+    /// timeout: restores the reserved quantity to `leaves_qty`, reverts
+    /// `ord_status` to its prior value (`New` or `PartiallyFilled`), and
+    /// reports `exec_type` (`ExecType::Rejected` or `ExecType::DoneForDay`)
+    /// referencing `match_id`. No-op if `match_id` isn't (or is no longer)
+    /// pending.
+    #[allow(dead_code)]
+    async fn rollback_match(&self, match_id: &MatchId, exec_type: ExecType) {
+        let Some(pending) = self.pending_matches.lock().await.remove(match_id) else { return };
+
+        let updated_order = {
+            let mut orders = self.orders.lock().await;
+            let Some(order) = orders.get_mut(&pending.cl_ord_id) else { return };
+            order.rollback_pending(pending.qty as u64);
+            order.clone()
+        };
+
+        println!("Rolling back match {} for order {}: {:?}", pending.match_id, updated_order.cl_ord_id, exec_type);
+        let _ = self.send_execution_report(&updated_order, exec_type).await;
+    }
+
+    /// Marks an order (or its unfilled residual) as canceled and reports it.
+    async fn apply_cancel(&self, cl_ord_id: &str) {
+        let updated_order = {
+            let mut orders = self.orders.lock().await;
+            let Some(order) = orders.get_mut(cl_ord_id) else { return };
+            order.leaves_qty = 0;
+            order.ord_status = OrdStatus::Canceled;
+            order.clone()
+        };
+
+        let _ = self.send_execution_report(&updated_order, ExecType::Canceled).await;
     }
 
     async fn send_execution_report(&self, order: &Order, exec_type: ExecType) -> Result<(), String> {
         if let Some(session) = &self.session {
-            let exec_report = ExecutionReport {
-                order_id: order.order_id.clone(),
-                cl_ord_id: order.cl_ord_id.clone(),
-                exec_id: Uuid::new_v4().to_string(),
-                exec_type,
-                ord_status: order.ord_status.clone(),
-                symbol: order.symbol.clone(),
-                side: order.side,
-                leaves_qty: order.leaves_qty,
-                cum_qty: order.cum_qty,
-                avg_px: order.avg_px,
-            };
-
-            // In a real implementation, this would use generated message encoding
-            let fix_message = format!(
-                "35=8|11={}|37={}|17={}|150={}|39={}|55={}|54={}|151={}|14={}|6={}",
-                exec_report.cl_ord_id,
-                exec_report.order_id,
-                exec_report.exec_id,
-                exec_type as u8,
-                order.ord_status as u8,
-                exec_report.symbol,
-                exec_report.side,
-                exec_report.leaves_qty,
-                exec_report.cum_qty,
-                exec_report.avg_px
-            );
-
-            session.send_raw(fix_message.as_bytes()).await
+            let exec_report = ExecutionReport::builder()
+                .order_id(order.order_id.clone())
+                .cl_ord_id(order.cl_ord_id.clone())
+                .exec_id(Uuid::new_v4().to_string())
+                .exec_type(exec_type)
+                .ord_status(order.ord_status)
+                .symbol(order.symbol.clone())
+                .side(order.side)
+                .leaves_qty(order.leaves_qty as i64)
+                .cum_qty(order.cum_qty as i64)
+                .avg_px(order.avg_px)
+                .build();
+
+            session.send_raw(&exec_report.encode()).await
                 .map_err(|e| format!("Failed to send execution report: {}", e))?;
 
             println!("Sent execution report: {:?} for order {}", exec_type, order.cl_ord_id);
@@ -214,6 +455,12 @@ impl OrderManager {
                 let updated_order = order.clone();
                 drop(orders);
 
+                let mut books = self.books.lock().await;
+                if let Some(book) = books.get_mut(&updated_order.symbol) {
+                    book.cancel(updated_order.side, updated_order.price.unwrap_or(0.0), &cl_ord_id);
+                }
+                drop(books);
+
                 self.send_execution_report(&updated_order, ExecType::Canceled).await?;
                 return Ok(());
             }
@@ -232,7 +479,7 @@ struct TradingHandler {
 impl TradingHandler {
     fn new(client_id: String) -> Self {
         Self {
-            order_manager: OrderManager::new(),
+            order_manager: OrderManager::new(client_id.clone()),
             client_id,
         }
     }
@@ -266,40 +513,30 @@ impl FixHandler for TradingHandler {
                     println!("Received admin message: {:?}", admin);
                 }
             }
-        } else {
-            // Parse application messages (in real implementation, use generated types)
-            let msg_str = String::from_utf8_lossy(msg.body());
-            println!("Received FIX message: {}", msg_str);
-
-            // Simple parsing for demonstration (real implementation would use proper FIX parsing)
-            if msg_str.contains("35=D") {
-                // New Order Single
-                println!("Processing New Order Single from {}", self.client_id);
-                
-                // In a real implementation, parse all fields properly
-                let cl_ord_id = format!("ORDER_{}", chrono::Utc::now().timestamp_millis());
-                let symbol = "EURUSD".to_string();
-                let side = '1'; // Buy
-                let qty = 1000000;
-                let price = Some(1.1234);
-                let ord_type = '2'; // Limit
-
-                if let Err(e) = self.order_manager.handle_new_order(
-                    cl_ord_id, symbol, side, qty, price, ord_type
-                ).await {
-                    println!("Order rejected: {}", e);
-                }
-            } else if msg_str.contains("35=F") {
-                // Order Cancel Request
-                println!("Processing Order Cancel Request from {}", self.client_id);
-                
-                // Extract ClOrdID (simplified)
-                let cl_ord_id = "ORDER_123".to_string(); // In reality, parse from message
-                
-                if let Err(e) = self.order_manager.handle_order_cancel(cl_ord_id).await {
-                    println!("Cancel rejected: {}", e);
-                }
+        } else if let Some(order) = msg.as_app::<OrderRequest>() {
+            println!("Processing New Order Single from {}", self.client_id);
+
+            let qty = order.quantity().unsigned_abs() as u64;
+            let price = (order.ord_type() == OrdType::Limit).then_some(order.price());
+
+            if let Err(e) = self.order_manager.handle_new_order(
+                order.cl_ord_id().to_string(),
+                order.symbol().to_string(),
+                order.side(),
+                qty,
+                price,
+                order.ord_type(),
+            ).await {
+                println!("Order rejected: {}", e);
             }
+        } else if let Some(cancel) = msg.as_app::<OrderCancelRequest>() {
+            println!("Processing Order Cancel Request from {}", self.client_id);
+
+            if let Err(e) = self.order_manager.handle_order_cancel(cancel.orig_cl_ord_id().to_string()).await {
+                println!("Cancel rejected: {}", e);
+            }
+        } else {
+            println!("Received unrecognized FIX message: {:?}", msg.body());
         }
     }
 
@@ -308,6 +545,123 @@ impl FixHandler for TradingHandler {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn manager_with_order(qty: u64) -> (OrderManager, String) {
+        let manager = OrderManager::new("TRADER1".to_string());
+        let cl_ord_id = "ORDER001".to_string();
+        let order = Order::new(cl_ord_id.clone(), "EURUSD".to_string(), Side::Buy, qty, Some(1.1), OrdType::Limit);
+        manager.orders.lock().await.insert(cl_ord_id.clone(), order);
+        (manager, cl_ord_id)
+    }
+
+    #[tokio::test]
+    async fn reserve_match_reserves_qty_on_first_match() {
+        let (manager, cl_ord_id) = manager_with_order(100).await;
+
+        let match_id = manager.reserve_match(&cl_ord_id, 40, 1.1).await;
+        assert!(match_id.is_some());
+
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.leaves_qty, 60, "reserved qty must come out of leaves_qty");
+        assert_eq!(order.pending_qty, 40);
+        assert_eq!(order.cum_qty, 0, "reserving must not commit a fill");
+
+        assert!(manager.pending_matches.lock().await.contains_key(&match_id.unwrap()));
+    }
+
+    #[tokio::test]
+    async fn reserve_match_unknown_order_returns_none() {
+        let manager = OrderManager::new("TRADER1".to_string());
+        assert!(manager.reserve_match("NOPE", 10, 1.0).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn confirm_match_commits_trade_and_updates_status() {
+        let (manager, cl_ord_id) = manager_with_order(100).await;
+        let match_id = manager.reserve_match(&cl_ord_id, 40, 1.1).await.unwrap();
+
+        manager.confirm_match(&match_id).await;
+
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.cum_qty, 40);
+        assert_eq!(order.pending_qty, 0, "confirming must release the reservation");
+        assert_eq!(order.leaves_qty, 60);
+        assert_eq!(order.ord_status, OrdStatus::PartiallyFilled);
+        assert!(!manager.pending_matches.lock().await.contains_key(&match_id), "confirming must clear the pending entry");
+
+        let trades = manager.trades_for(&cl_ord_id).await;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].qty, 40);
+    }
+
+    #[tokio::test]
+    async fn confirm_match_fully_filled_order_is_marked_filled() {
+        let (manager, cl_ord_id) = manager_with_order(40).await;
+        let match_id = manager.reserve_match(&cl_ord_id, 40, 1.1).await.unwrap();
+
+        manager.confirm_match(&match_id).await;
+
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.leaves_qty, 0);
+        assert_eq!(order.ord_status, OrdStatus::Filled);
+    }
+
+    #[tokio::test]
+    async fn confirm_match_unknown_match_id_is_a_no_op() {
+        let (manager, cl_ord_id) = manager_with_order(100).await;
+        manager.confirm_match(&"not-a-real-match".to_string()).await;
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.cum_qty, 0);
+        assert_eq!(order.leaves_qty, 100);
+    }
+
+    #[tokio::test]
+    async fn rollback_match_restores_leaves_qty_and_reverts_to_new() {
+        let (manager, cl_ord_id) = manager_with_order(100).await;
+        let match_id = manager.reserve_match(&cl_ord_id, 40, 1.1).await.unwrap();
+
+        manager.rollback_match(&match_id, ExecType::Rejected).await;
+
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.pending_qty, 0);
+        assert_eq!(order.leaves_qty, 100, "a rolled-back reservation must be restored to leaves_qty");
+        assert_eq!(order.cum_qty, 0);
+        assert_eq!(order.ord_status, OrdStatus::New);
+        assert!(!manager.pending_matches.lock().await.contains_key(&match_id));
+    }
+
+    #[tokio::test]
+    async fn rollback_match_with_prior_fill_reverts_to_partially_filled() {
+        let (manager, cl_ord_id) = manager_with_order(100).await;
+
+        // First match confirms, leaving the order PartiallyFilled...
+        let first = manager.reserve_match(&cl_ord_id, 30, 1.1).await.unwrap();
+        manager.confirm_match(&first).await;
+
+        // ...then a second match is reserved but its execution fails.
+        let second = manager.reserve_match(&cl_ord_id, 20, 1.1).await.unwrap();
+        manager.rollback_match(&second, ExecType::Rejected).await;
+
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.cum_qty, 30, "rollback must not touch the already-confirmed fill");
+        assert_eq!(order.pending_qty, 0);
+        assert_eq!(order.leaves_qty, 70, "the rolled-back 20 must be restored on top of the confirmed 30");
+        assert_eq!(order.ord_status, OrdStatus::PartiallyFilled);
+    }
+
+    #[tokio::test]
+    async fn rollback_match_unknown_match_id_is_a_no_op() {
+        let (manager, cl_ord_id) = manager_with_order(100).await;
+        manager.rollback_match(&"not-a-real-match".to_string(), ExecType::Rejected).await;
+        let order = manager.orders.lock().await.get(&cl_ord_id).unwrap().clone();
+        assert_eq!(order.leaves_qty, 100);
+        assert_eq!(order.ord_status, OrdStatus::New);
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Starting Order Management System (Artio-style)");
@@ -343,18 +697,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let session_clone = session.clone();
     tokio::spawn(async move {
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-        
+
         // Send a new order
-        let new_order = "35=D|11=ORDER001|55=EURUSD|54=1|38=1000000|40=2|44=1.1234";
+        let new_order = OrderRequest::builder()
+            .cl_ord_id("ORDER001")
+            .symbol("EURUSD")
+            .side(Side::Buy)
+            .quantity(1_000_000)
+            .price(1.1234)
+            .ord_type(OrdType::Limit)
+            .build();
         println!("Sending new order...");
-        let _ = session_clone.send_raw(new_order.as_bytes()).await;
+        let _ = session_clone.send_raw(&new_order.encode()).await;
 
         tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-        
+
         // Send another order
-        let new_order2 = "35=D|11=ORDER002|55=GBPUSD|54=2|38=500000|40=1";
+        let new_order2 = OrderRequest::builder()
+            .cl_ord_id("ORDER002")
+            .symbol("GBPUSD")
+            .side(Side::Sell)
+            .quantity(500_000)
+            .ord_type(OrdType::Market)
+            .build();
         println!("Sending second order...");
-        let _ = session_clone.send_raw(new_order2.as_bytes()).await;
+        let _ = session_clone.send_raw(&new_order2.encode()).await;
     });
 
     println!("Order Management System running...");
@@ -1,13 +1,22 @@
 use crate::config::FixClientConfig;
 use crate::error::{FixgError, Result};
 use crate::gateway::{GatewayHandle, GatewayToClientEvent, GatewayClientCommand, GatewaySessionHandle};
-use crate::session::{new_session, DisconnectReason, Session, SessionConfig, OutboundPayload};
+use crate::session::{new_session, new_session_with_backpressure_policy, BootstrapGate, DisconnectReason, Session, SessionConfig, OutboundPayload};
+use crate::journal::{JournalRecord, TraceLevel};
+use crate::session::SendAck;
+use crate::transport::TransportConfig;
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::Stream;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 use tokio::sync::{mpsc, oneshot};
-use crate::messages::AdminMessage;
+use crate::messages::{AdminMessage, AppMessage, ExecutionReport, OrderRequest};
 use crate::protocol;
-use std::collections::HashMap; // Assuming HashMap is used based on the changes
+use std::collections::{HashMap, HashSet}; // Assuming HashMap is used based on the changes
+use std::sync::Arc;
+use tokio::time::Duration;
 
 /// Represents an inbound FIX message received from a counterparty.
 /// 
@@ -26,6 +35,14 @@ impl InboundMessage {
     pub fn msg_type(&self) -> &str { &self.msg_type }
     pub fn body(&self) -> &Bytes { &self.payload }
     pub fn admin(&self) -> Option<&AdminMessage> { self.admin.as_ref() }
+
+    /// Decodes this message's payload as a typed `T`, returning `None` if it
+    /// isn't a well-formed `T::MSG_TYPE` message. Lets handlers match on
+    /// `NewOrderSingle`/`OrderCancelRequest`/etc. directly instead of
+    /// substring-matching `body()` for a MsgType(35) tag.
+    pub fn as_app<T: AppMessage>(&self) -> Option<T> {
+        T::parse(&self.payload).ok()
+    }
 }
 
 #[async_trait]
@@ -33,6 +50,37 @@ pub trait FixHandler: Send {
     async fn on_message(&mut self, _session: &Session, _msg: InboundMessage) {}
     async fn on_session_active(&mut self, _session: &Session) {}
     async fn on_disconnect(&mut self, _session: &Session, _reason: DisconnectReason) {}
+    /// Called before each automatic reconnect attempt (see `ReconnectConfig`).
+    async fn on_reconnecting(&mut self, _session: &Session, _attempt: u32, _delay_ms: u64) {}
+    /// Called when a sequence gap was detected and a ResendRequest(35=2)
+    /// covering `[from, to]` has been sent to recover it.
+    async fn on_resend_in_progress(&mut self, _session: &Session, _from: u32, _to: u32) {}
+    /// Called once a gap-recovery round trip finishes, whether it was a gap
+    /// we detected and requested resent, or a range the peer requested from
+    /// us. `recovered` is the number of application messages replayed or
+    /// received, excluding any admin-only sub-range gap-filled instead.
+    async fn on_recovery_complete(&mut self, _session: &Session, _recovered: u32) {}
+    /// Called when the peer has been silent for longer than the heartbeat
+    /// interval, right before the gateway sends an automatic TestRequest(35=1)
+    /// probing whether it's still there. If no matching Heartbeat(35=0)
+    /// arrives in time, the session disconnects with
+    /// `DisconnectReason::HeartbeatTimeout`.
+    async fn on_heartbeat_timeout(&mut self, _session: &Session) {}
+}
+
+/// Borrowing adapter returned by [`FixClient::events`] that exposes inbound
+/// gateway events as a [`futures_util::Stream`] instead of the plain
+/// `mpsc::Receiver` `run`'s dispatch loop drives directly.
+pub struct EventStream<'a> {
+    rx: &'a mut mpsc::Receiver<GatewayToClientEvent>,
+}
+
+impl<'a> Stream for EventStream<'a> {
+    type Item = GatewayToClientEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().rx.poll_recv(cx)
+    }
 }
 
 /// FIX client for connecting to and interacting with a FIX gateway.
@@ -46,8 +94,22 @@ pub struct FixClient {
     events_rx: mpsc::Receiver<GatewayToClientEvent>, // Changed from event_rx to events_rx as per original code
     /// Channel for sending commands to the gateway
     cmd_tx: mpsc::Sender<GatewayClientCommand>, // Added cmd_tx from original code
-    /// The current active session, if any
-    current_session: Option<Session>, // Changed from sessions to current_session as per original code
+    /// Every session this client currently knows about, keyed by `session_id`,
+    /// so `run` can route each event to the `Session` it names instead of
+    /// assuming a single active session.
+    sessions: HashMap<u64, Session>,
+    /// Retained for convenience APIs (e.g. `replay_journal`) that talk to the
+    /// gateway directly rather than through `cmd_tx`'s per-client channel.
+    handle: GatewayHandle,
+    /// Orders awaiting their `ExecutionReport`, keyed by ClOrdID, resolved
+    /// from `run`'s inbound-message dispatch.
+    pending_orders: HashMap<String, oneshot::Sender<ExecutionReport>>,
+    /// Startup barrier from `FixClientConfig::bootstrap_delay`. `None` if
+    /// unconfigured, the prior behavior of sending immediately.
+    bootstrap_gate: Option<Arc<BootstrapGate>>,
+    /// Session ids `run` has already reported to `bootstrap_gate`, so a
+    /// reconnect's repeat `SessionActive` doesn't recount the same session.
+    bootstrapped_sessions: HashSet<u64>,
 }
 
 impl FixClient {
@@ -57,7 +119,11 @@ impl FixClient {
             library_id: config.library_id,
             events_rx: conn.events_rx,
             cmd_tx: conn.cmd_tx,
-            current_session: None,
+            sessions: HashMap::new(),
+            handle,
+            pending_orders: HashMap::new(),
+            bootstrap_gate: config.bootstrap_delay.map(|delay| Arc::new(BootstrapGate::new(delay))),
+            bootstrapped_sessions: HashSet::new(),
         })
     }
 
@@ -70,6 +136,12 @@ impl FixClient {
                 sender_comp_id: cfg.sender_comp_id.clone(),
                 target_comp_id: cfg.target_comp_id.clone(),
                 heartbeat_interval_secs: cfg.heartbeat_interval_secs,
+                reconnect: cfg.reconnect.clone(),
+                reset_seq_num: cfg.reset_seq_num,
+                transport: cfg.transport.clone(),
+                begin_string: cfg.begin_string.clone(),
+                default_appl_ver_id: cfg.default_appl_ver_id.clone(),
+                compression: cfg.compression.clone(),
                 respond_to: tx,
             })
             .await
@@ -80,64 +152,247 @@ impl FixClient {
         let cmd_tx = self.cmd_tx.clone();
         let sender_comp_id = cfg.sender_comp_id.clone();
         let target_comp_id = cfg.target_comp_id.clone();
-        let (session, mut out_rx) = new_session(session_id);
+        let (session, mut out_rx) = new_session_with_backpressure_policy(
+            session_id,
+            handle.transport,
+            cfg.backpressure_policy,
+        );
 
         // Route outbound payloads to gateway with session id
         tokio::spawn(async move {
             while let Some(payload) = out_rx.recv().await {
                 match payload {
                     OutboundPayload::Raw(bytes) => {
+                        let (tx, rx) = oneshot::channel();
                         let _ = cmd_tx
-                            .send(GatewayClientCommand::Send { session_id, payload: bytes })
+                            .send(GatewayClientCommand::Send {
+                                session_id,
+                                payload: bytes,
+                                respond_to: tx,
+                            })
                             .await;
+                        if let Ok(SendAck::Rejected) = rx.await {
+                            tracing::warn!(session_id, "outbound send rejected: session queue full or gone");
+                        }
                     }
                     OutboundPayload::Admin(msg) => {
+                        let (tx, rx) = oneshot::channel();
                         let _ = cmd_tx
                             .send(GatewayClientCommand::SendAdmin {
                                 session_id,
                                 msg,
                                 sender_comp_id: sender_comp_id.clone(),
                                 target_comp_id: target_comp_id.clone(),
+                                respond_to: tx,
                             })
                             .await;
+                        if let Ok(SendAck::Rejected) = rx.await {
+                            tracing::warn!(session_id, "outbound send rejected: session queue full or gone");
+                        }
+                    }
+                    OutboundPayload::Flush(ack) => {
+                        let (tx, rx) = oneshot::channel();
+                        let _ = cmd_tx
+                            .send(GatewayClientCommand::Flush { session_id, respond_to: tx })
+                            .await;
+                        let _ = rx.await;
+                        let _ = ack.send(());
                     }
                 }
             }
         });
 
-        self.current_session = Some(session.clone());
+        let session = match &self.bootstrap_gate {
+            Some(gate) => {
+                gate.register_session();
+                session.with_bootstrap_gate(gate.clone())
+            }
+            None => session,
+        };
+        self.sessions.insert(session_id, session.clone());
         Ok(session)
     }
 
+    /// Starts an acceptor on `bind_addr` owned by this client, permitting
+    /// only the given `(sender_comp_id, target_comp_id)` pairs. Accepted
+    /// sessions surface through [`FixClient::run`] the same as initiated
+    /// ones, and can be replied to from the handler's `Session` once
+    /// `on_session_active` fires.
+    pub async fn listen(
+        &mut self,
+        bind_addr: SocketAddr,
+        expected_sessions: Vec<(String, String)>,
+    ) -> Result<()> {
+        self.listen_with_transport(bind_addr, expected_sessions, None, Vec::new())
+            .await
+    }
+
+    /// Like [`listen`](Self::listen), but overrides the gateway's configured
+    /// transport for this acceptor only, and offers `compression` codecs (in
+    /// preference order) during the post-connect capability handshake with
+    /// each connecting initiator.
+    pub async fn listen_with_transport(
+        &mut self,
+        bind_addr: SocketAddr,
+        expected_sessions: Vec<(String, String)>,
+        transport: Option<TransportConfig>,
+        compression: Vec<crate::transport::CompressionKind>,
+    ) -> Result<()> {
+        if let Some(gate) = &self.bootstrap_gate {
+            for _ in 0..expected_sessions.len() {
+                gate.register_session();
+            }
+        }
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayClientCommand::Listen {
+                bind_addr,
+                expected_sessions,
+                transport,
+                compression,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)?
+    }
+
+    /// Raises or lowers `session_id`'s compliance-journal capture verbosity
+    /// at runtime, without restarting the session.
+    pub async fn set_trace_level(&mut self, session_id: u64, level: TraceLevel) -> Result<()> {
+        self.cmd_tx
+            .send(GatewayClientCommand::SetTraceLevel { session_id, level })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)
+    }
+
+    /// Sends `order` on `session_id` and resolves once the matching
+    /// `ExecutionReport` (by ClOrdID) arrives through `run`'s dispatch loop,
+    /// or fails if none arrives within `timeout`. The "send receipt"
+    /// pattern: the caller gets a handle back for a message that was fired
+    /// off fully asynchronously.
+    pub async fn send_order(
+        &mut self,
+        session_id: u64,
+        order: OrderRequest,
+        timeout: Duration,
+    ) -> Result<ExecutionReport> {
+        let session = self
+            .sessions
+            .get(&session_id)
+            .ok_or(FixgError::ChannelClosed)?
+            .clone();
+        let cl_ord_id = order.cl_ord_id().to_string();
+        let (tx, rx) = oneshot::channel();
+        self.pending_orders.insert(cl_ord_id.clone(), tx);
+        if let Err(e) = session.send(order.into()).await {
+            self.pending_orders.remove(&cl_ord_id);
+            return Err(e);
+        }
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(report)) => Ok(report),
+            _ => {
+                self.pending_orders.remove(&cl_ord_id);
+                Err(FixgError::Session(format!(
+                    "no ExecutionReport received for ClOrdID {cl_ord_id} within timeout"
+                )))
+            }
+        }
+    }
+
+    /// Re-reads the compliance journal for `session_id` over
+    /// `[from_ts, to_ts]` (milliseconds since epoch), for post-trade analysis
+    /// or serving a ResendRequest offline. Delegates to
+    /// [`GatewayHandle::replay_journal`].
+    pub async fn replay_journal(
+        &self,
+        session_id: u64,
+        from_ts: u64,
+        to_ts: u64,
+    ) -> Result<Vec<JournalRecord>> {
+        self.handle.replay_journal(session_id, from_ts, to_ts).await
+    }
+
+    /// Exposes inbound gateway events as a [`Stream`], for callers who want
+    /// `StreamExt` combinators (`next`, `filter`, `select!` against other
+    /// streams, etc.) instead of driving [`FixClient::run`]'s fixed dispatch
+    /// loop.
+    pub fn events(&mut self) -> EventStream<'_> {
+        EventStream { rx: &mut self.events_rx }
+    }
+
     pub async fn run<H: FixHandler>(&mut self, handler: &mut H) -> Result<()> {
         while let Some(event) = self.events_rx.recv().await {
             match event {
-                GatewayToClientEvent::SessionActive { session_id } => {
-                    if self.current_session.is_none() {
-                        let (session, _out_rx) = new_session(session_id);
-                        self.current_session = Some(session);
-                    }
-                    if let Some(ref session) = self.current_session {
-                        handler.on_session_active(session).await;
+                GatewayToClientEvent::SessionActive { session_id, identity: _, transport } => {
+                    let session = self.sessions.entry(session_id).or_insert_with(|| {
+                        let (session, _out_rx) = new_session(session_id, transport);
+                        session
+                    });
+                    handler.on_session_active(session).await;
+                    if let Some(gate) = &self.bootstrap_gate {
+                        if self.bootstrapped_sessions.insert(session_id) {
+                            gate.mark_active();
+                        }
                     }
                 }
-                GatewayToClientEvent::InboundMessage { session_id: _, msg_type, payload } => {
-                    if let Some(ref session) = self.current_session {
-                        // Try to parse typed admin message
-                        let admin = match protocol::decode(&payload) {
-                            Ok(ref m) => AdminMessage::try_from(m).ok(),
-                            Err(_) => None,
-                        };
+                GatewayToClientEvent::InboundMessage { session_id, msg_type, payload } => {
+                    let decoded = protocol::decode(&payload).ok();
+                    if let Some(report) = decoded.as_ref().and_then(|m| ExecutionReport::try_from(m).ok()) {
+                        if let Some(tx) = self.pending_orders.remove(report.cl_ord_id()) {
+                            let _ = tx.send(report);
+                        }
+                    }
+                    if let Some(session) = self.sessions.get(&session_id) {
+                        let admin = decoded.as_ref().and_then(|m| AdminMessage::try_from(m).ok());
                         handler
                             .on_message(session, InboundMessage { msg_type, payload, admin })
                             .await;
                     }
                 }
-                GatewayToClientEvent::Disconnected { session_id: _, reason } => {
-                    if let Some(ref session) = self.current_session {
+                GatewayToClientEvent::Disconnected { session_id, reason } => {
+                    // Don't remove the session here: for a retryable `reason`
+                    // the gateway is about to reconnect this same
+                    // `session_id` transparently (see `ReconnectConfig`), and
+                    // the `Reconnecting`/`ResendInProgress`/`RecoveryComplete`
+                    // events that follow are looked up by `session_id` below
+                    // -- removing it on every disconnect silently dropped
+                    // those callbacks (and handed `on_session_active` a
+                    // brand-new, disconnected `Session` once the reconnect
+                    // landed, instead of the one the application is holding).
+                    if let Some(session) = self.sessions.get(&session_id) {
                         handler.on_disconnect(session, reason).await;
                     }
                 }
+                GatewayToClientEvent::Reconnecting { session_id, attempt, delay_ms } => {
+                    if let Some(session) = self.sessions.get(&session_id) {
+                        handler.on_reconnecting(session, attempt, delay_ms).await;
+                    }
+                }
+                GatewayToClientEvent::ResendInProgress { session_id, from, to } => {
+                    if let Some(session) = self.sessions.get(&session_id) {
+                        handler.on_resend_in_progress(session, from, to).await;
+                    }
+                }
+                GatewayToClientEvent::RecoveryComplete { session_id, recovered } => {
+                    if let Some(session) = self.sessions.get(&session_id) {
+                        handler.on_recovery_complete(session, recovered).await;
+                    }
+                }
+                GatewayToClientEvent::HeartbeatTimeout { session_id } => {
+                    if let Some(session) = self.sessions.get(&session_id) {
+                        handler.on_heartbeat_timeout(session).await;
+                    }
+                }
+                GatewayToClientEvent::SessionTerminated { session_id } => {
+                    // The gateway has given up on this session for good (an
+                    // accepted connection closed, or an initiator's
+                    // reconnect loop is no longer retrying) -- unlike
+                    // `Disconnected`, no further reconnect is coming, so
+                    // it's safe, and necessary, to drop it here.
+                    self.sessions.remove(&session_id);
+                    self.bootstrapped_sessions.remove(&session_id);
+                }
             }
         }
         Err(FixgError::ChannelClosed)
@@ -0,0 +1,250 @@
+//! A per-data-directory control socket.
+//!
+//! `Gateway::spawn` uses this to enforce a one-process-per-journal
+//! invariant: two gateways pointed at the same `GatewayConfig::log_directory`
+//! would otherwise race each other for outbound/inbound sequence-number
+//! state in [`crate::storage`]. The same socket doubles as a small runtime
+//! admin interface (list sessions, request a session logout, trigger
+//! shutdown) over a length-delimited JSON request/response protocol, so an
+//! operator (or a CLI wrapping this crate) can reach a running gateway
+//! without going through a registered `FixClient`.
+use crate::error::{FixgError, Result};
+use crate::gateway::GatewayHandle;
+use crate::storage::SessionKey;
+use bytes::Bytes;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+
+/// Derives the control socket path for a `log_directory`. Kept as a
+/// function (rather than a fixed name) so callers that only know
+/// `log_directory` -- e.g. a separate CLI process -- can find the socket a
+/// running gateway bound without otherwise sharing config.
+pub fn control_socket_path(log_directory: &Path) -> PathBuf {
+    log_directory.join(".fixg-control.sock")
+}
+
+/// A request sent over the control socket, either by a second process that
+/// lost the singleton race or by an admin tool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ControlRequest {
+    /// Confirms a gateway is listening and reports how many sessions are
+    /// currently active.
+    Status,
+    /// Lists every session the gateway currently knows a `SessionKey` for.
+    ListSessions,
+    /// Requests a best-effort Logout(35=5) for a single live session.
+    LogoutSession { session_id: u64 },
+    /// Triggers `GatewayHandle::shutdown` on the running instance.
+    Shutdown,
+}
+
+/// The response to a [`ControlRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ControlResponse {
+    /// Reply to `Status`.
+    Status { active_sessions: u64 },
+    /// Reply to `ListSessions`.
+    Sessions(Vec<ControlSessionSummary>),
+    /// Reply to a request that otherwise has nothing to report back.
+    Ack,
+    /// The request reached the gateway but couldn't be carried out, e.g.
+    /// `LogoutSession` naming a `session_id` that isn't active.
+    Error(String),
+}
+
+/// One entry of a [`ControlResponse::Sessions`] listing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlSessionSummary {
+    pub session_id: u64,
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+}
+
+/// Outcome of attempting to claim the control socket for a `log_directory`.
+pub enum ControlClaim {
+    /// No other gateway holds this `log_directory`; the listener is ready
+    /// for [`serve`].
+    Bound(ControlListener),
+    /// Another process is already listening on this `log_directory`'s
+    /// control socket.
+    AlreadyRunning,
+}
+
+#[cfg(unix)]
+mod platform {
+    use super::*;
+    use tokio::net::{UnixListener, UnixStream};
+
+    pub type ControlListener = UnixListener;
+    pub type ControlStream = UnixStream;
+
+    pub async fn claim(path: &Path) -> std::io::Result<ControlClaim> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        match UnixListener::bind(path) {
+            Ok(listener) => {
+                restrict_to_owner(path)?;
+                Ok(ControlClaim::Bound(listener))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
+                // The path exists, but that's ambiguous between a live
+                // listener and a stale socket file a crashed process left
+                // behind -- only a connect attempt tells them apart.
+                match UnixStream::connect(path).await {
+                    Ok(_) => Ok(ControlClaim::AlreadyRunning),
+                    Err(_) => {
+                        std::fs::remove_file(path)?;
+                        let listener = UnixListener::bind(path)?;
+                        restrict_to_owner(path)?;
+                        Ok(ControlClaim::Bound(listener))
+                    }
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Locks the socket down to owner-only (0600) right after `bind`: the
+    /// control protocol (`control.rs` module docs) authenticates callers by
+    /// nothing but "can reach this path", and `dispatch` services
+    /// `Shutdown`/`LogoutSession` with no further checks, so anyone else who
+    /// could connect could stop or disrupt a live trading gateway.
+    fn restrict_to_owner(path: &Path) -> std::io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+    }
+
+    pub async fn connect(path: &Path) -> std::io::Result<ControlStream> {
+        UnixStream::connect(path).await
+    }
+
+    pub async fn accept(listener: &ControlListener) -> std::io::Result<ControlStream> {
+        Ok(listener.accept().await?.0)
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    use super::*;
+
+    /// Named-pipe control sockets aren't implemented yet on this platform;
+    /// every claim looks uncontested rather than silently corrupting a
+    /// shared journal, but `serve`/`send_control_request` have nothing to
+    /// bind or connect to here.
+    pub struct ControlListener;
+    pub struct ControlStream;
+
+    pub async fn claim(_path: &Path) -> std::io::Result<ControlClaim> {
+        Ok(ControlClaim::Bound(ControlListener))
+    }
+
+    pub async fn connect(_path: &Path) -> std::io::Result<ControlStream> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "control socket is only implemented on unix so far",
+        ))
+    }
+
+    pub async fn accept(_listener: &ControlListener) -> std::io::Result<ControlStream> {
+        std::future::pending().await
+    }
+}
+
+pub use platform::ControlListener;
+use platform::ControlStream;
+
+/// Attempts to claim the control socket derived from `log_directory`. See
+/// [`ControlClaim`] for what the two outcomes mean.
+pub async fn claim_control_socket(log_directory: &Path) -> std::io::Result<ControlClaim> {
+    platform::claim(&control_socket_path(log_directory)).await
+}
+
+/// Serves control requests on `listener` for as long as `handle`'s gateway
+/// is alive, forwarding each to the corresponding `GatewayHandle` method.
+/// Run as a detached background task from `Gateway::spawn`; it never
+/// returns control to its caller under normal operation.
+pub async fn serve(listener: ControlListener, handle: GatewayHandle) {
+    loop {
+        let stream = match platform::accept(&listener).await {
+            Ok(s) => s,
+            Err(e) => {
+                tracing::error!(error = %e, "control socket accept failed");
+                continue;
+            }
+        };
+        let handle = handle.clone();
+        tokio::spawn(async move {
+            if let Err(e) = serve_one(stream, &handle).await {
+                tracing::debug!(error = %e, "control connection ended");
+            }
+        });
+    }
+}
+
+async fn serve_one(stream: ControlStream, handle: &GatewayHandle) -> Result<()> {
+    let mut framed = Framed::new(stream, LengthDelimitedCodec::new());
+    while let Some(frame) = framed.next().await {
+        let frame = frame.map_err(FixgError::Io)?;
+        let request: ControlRequest =
+            serde_json::from_slice(&frame).map_err(|e| FixgError::Protocol(e.to_string()))?;
+        let response = dispatch(request, handle).await;
+        let body = serde_json::to_vec(&response).map_err(|e| FixgError::Protocol(e.to_string()))?;
+        framed.send(Bytes::from(body)).await.map_err(FixgError::Io)?;
+    }
+    Ok(())
+}
+
+async fn dispatch(request: ControlRequest, handle: &GatewayHandle) -> ControlResponse {
+    match request {
+        ControlRequest::Status => match handle.get_meter(None).await {
+            Ok(snapshot) => ControlResponse::Status { active_sessions: snapshot.active_sessions },
+            Err(e) => ControlResponse::Error(e.to_string()),
+        },
+        ControlRequest::ListSessions => match handle.list_sessions().await {
+            Ok(sessions) => ControlResponse::Sessions(
+                sessions
+                    .into_iter()
+                    .map(|(session_id, SessionKey { sender_comp_id, target_comp_id })| {
+                        ControlSessionSummary { session_id, sender_comp_id, target_comp_id }
+                    })
+                    .collect(),
+            ),
+            Err(e) => ControlResponse::Error(e.to_string()),
+        },
+        ControlRequest::LogoutSession { session_id } => {
+            match handle.logout_session(session_id).await {
+                Ok(()) => ControlResponse::Ack,
+                Err(e) => ControlResponse::Error(e.to_string()),
+            }
+        }
+        ControlRequest::Shutdown => match handle.shutdown().await {
+            Ok(()) => ControlResponse::Ack,
+            Err(e) => ControlResponse::Error(e.to_string()),
+        },
+    }
+}
+
+/// Sends a single `ControlRequest` to whatever gateway is listening on
+/// `log_directory`'s control socket and returns its response. This is how a
+/// second process that lost the singleton race (or an admin CLI) reaches a
+/// running instance instead of double-binding.
+pub async fn send_control_request(
+    log_directory: &Path,
+    request: &ControlRequest,
+) -> Result<ControlResponse> {
+    let stream = platform::connect(&control_socket_path(log_directory))
+        .await
+        .map_err(FixgError::Io)?;
+    let mut framed = Framed::new(stream, LengthDelimitedCodec::new());
+    let body = serde_json::to_vec(request).map_err(|e| FixgError::Protocol(e.to_string()))?;
+    framed.send(Bytes::from(body)).await.map_err(FixgError::Io)?;
+    let frame = framed
+        .next()
+        .await
+        .ok_or(FixgError::ChannelClosed)?
+        .map_err(FixgError::Io)?;
+    serde_json::from_slice(&frame).map_err(|e| FixgError::Protocol(e.to_string()))
+}
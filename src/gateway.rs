@@ -1,21 +1,27 @@
-use crate::config::GatewayConfig;
+use crate::config::{AuthOutcome, GatewayConfig, LogonRequest};
 use crate::error::{FixgError, Result};
 use crate::session::DisconnectReason;
-use bytes::{Bytes, BytesMut};
+use bytes::Bytes;
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::{
     atomic::{AtomicU64, Ordering},
     Arc,
 };
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::{mpsc, oneshot, RwLock};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{mpsc, oneshot, watch, RwLock};
 use tokio::time::{self, Duration, Instant};
 
+use crate::codec::FixCodec;
+use crate::control;
+use crate::journal::{make_journal, JournalRecord, JournalSink, TraceLevel, TraceLevelHandle};
 use crate::messages::AdminMessage;
 use crate::protocol::{self, FixMsgType};
-use crate::session::OutboundPayload;
+use crate::session::{OutboundPayload, SendAck};
 use crate::storage::{make_store, Direction, SessionKey};
+use crate::transport::{make_transport, TransportConfig, TransportKind, TransportWrite};
+use futures_util::StreamExt;
+use tokio_util::codec::FramedRead;
 
 fn now_millis() -> u64 {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -25,6 +31,1368 @@ fn now_millis() -> u64 {
         .as_millis() as u64
 }
 
+/// Generates a per-session nonce for the Logon challenge/response handshake,
+/// seeded from the OS RNG via `RandomState` (std-only, no extra dependency).
+fn generate_nonce(session_id: u64) -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hash, Hasher};
+    let mut hasher = RandomState::new().build_hasher();
+    session_id.hash(&mut hasher);
+    now_millis().hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Appends one message to the compliance journal, honoring the session's
+/// current `TraceLevel`: `Off` journals nothing, `Admin` journals only
+/// session-level message types, `Full` journals everything.
+async fn journal_record(
+    journal: &Arc<dyn JournalSink>,
+    trace: &Arc<TraceLevelHandle>,
+    sess_key: &SessionKey,
+    direction: Direction,
+    seq: Option<u32>,
+    msg_type: &str,
+    payload: &[u8],
+) {
+    let level = trace.get();
+    if level == TraceLevel::Off {
+        return;
+    }
+    if level == TraceLevel::Admin && !is_admin_msg_type_str(msg_type) {
+        return;
+    }
+    let _ = journal
+        .record(JournalRecord {
+            session: sess_key.clone(),
+            direction,
+            seq,
+            msg_type: msg_type.to_string(),
+            ts_millis: now_millis(),
+            payload_b64: base64_encode(payload),
+        })
+        .await;
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    use base64::{engine::general_purpose, Engine as _};
+    general_purpose::STANDARD.encode(data)
+}
+
+fn is_admin_msg_type_str(msg_type: &str) -> bool {
+    matches!(msg_type, "A" | "0" | "1" | "5" | "2" | "4")
+}
+
+/// Queues `payload` on `tx` (the session's outbound writer channel) without
+/// blocking, and replies with the `SendAck` this produced: `Rejected` if
+/// `tx` is `None` (no such session) or its queue is full, `Queued` (plus a
+/// `GatewayEvent::Backpressure`) if the queue is at or above
+/// `high_water_mark` after this send, `Accepted` otherwise.
+async fn try_enqueue_outbound(
+    tx: Option<mpsc::Sender<OutboundPayload>>,
+    payload: OutboundPayload,
+    session_id: u64,
+    high_water_mark: usize,
+    to_client_tx: &mpsc::Sender<GatewayEvent>,
+    respond_to: oneshot::Sender<SendAck>,
+) {
+    let ack = match tx {
+        Some(tx) => match tx.try_send(payload) {
+            Ok(()) => {
+                let used = tx.max_capacity().saturating_sub(tx.capacity());
+                if used >= high_water_mark {
+                    let _ = to_client_tx
+                        .send(GatewayEvent::Backpressure { session_id })
+                        .await;
+                    SendAck::Queued
+                } else {
+                    SendAck::Accepted
+                }
+            }
+            Err(_) => SendAck::Rejected,
+        },
+        None => SendAck::Rejected,
+    };
+    let _ = respond_to.send(ack);
+}
+
+/// Returns true for message types that are session-level (administrative)
+/// rather than application messages, and therefore must not be replayed
+/// verbatim during a resend — they are gap-filled instead.
+fn is_admin_msg_type(mt: &FixMsgType) -> bool {
+    matches!(
+        mt,
+        FixMsgType::Logon
+            | FixMsgType::Heartbeat
+            | FixMsgType::TestRequest
+            | FixMsgType::Logout
+            | FixMsgType::ResendRequest
+            | FixMsgType::SequenceReset
+    )
+}
+
+/// Sends a SequenceReset-GapFill covering `[gap_begin_seq, new_seq_no)`.
+async fn send_gap_fill(
+    write_half: &mut TransportWrite,
+    store: &Arc<dyn crate::storage::MessageStore>,
+    sess_key: &SessionKey,
+    gap_begin_seq: u32,
+    new_seq_no: u32,
+    out_seq_num: &mut u32,
+    sender_comp_id: &str,
+    target_comp_id: &str,
+    meters: &Arc<SessionMeters>,
+    journal: &Arc<dyn JournalSink>,
+    trace: &Arc<TraceLevelHandle>,
+) {
+    let mut reset = protocol::build_sequence_reset(new_seq_no, true, sender_comp_id, target_comp_id);
+    reset.set_field(34, gap_begin_seq.to_string());
+    let bytes = protocol::encode(reset);
+    let _ = write_half.write_all(&bytes).await;
+    let _ = store
+        .append_bytes(sess_key, Direction::Outbound, Some(gap_begin_seq), now_millis(), bytes.as_ref())
+        .await;
+    journal_record(journal, trace, sess_key, Direction::Outbound, Some(gap_begin_seq), "4", bytes.as_ref()).await;
+    *out_seq_num = (*out_seq_num).max(new_seq_no);
+    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+    meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+    meters.gap_fills_sent.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Replays stored outbound messages `[begin_seq, end_seq]` in response to a
+/// peer's ResendRequest(35=2). Contiguous runs of administrative messages
+/// (which must never be replayed verbatim) are collapsed into a single
+/// SequenceReset-GapFill; application messages are resent as-is with
+/// PossDupFlag(43=Y) and OrigSendingTime(122) set from the original
+/// SendingTime. Returns the number of application messages actually
+/// replayed, for a caller-emitted `GatewayEvent::RecoveryComplete`.
+async fn replay_resend_range(
+    write_half: &mut TransportWrite,
+    store: &Arc<dyn crate::storage::MessageStore>,
+    sess_key: &SessionKey,
+    begin_seq: u32,
+    end_seq: u32,
+    out_seq_num: &mut u32,
+    sender_comp_id: &str,
+    target_comp_id: &str,
+    meters: &Arc<SessionMeters>,
+    journal: &Arc<dyn JournalSink>,
+    trace: &Arc<TraceLevelHandle>,
+) -> u32 {
+    let chunks = store
+        .load_outbound_range(sess_key, begin_seq, end_seq)
+        .await
+        .unwrap_or_default();
+    let mut recovered: u32 = 0;
+
+    if chunks.is_empty() {
+        // Nothing recoverable for this range (store error, retention/TTL
+        // eviction, or a restart that lost an in-memory store). Gap-fill the
+        // whole requested range rather than leaving the peer's
+        // ResendRequest unanswered and waiting forever.
+        send_gap_fill(write_half, store, sess_key, begin_seq, end_seq.saturating_add(1), out_seq_num, sender_comp_id, target_comp_id, meters, journal, trace).await;
+        return 0;
+    }
+
+    let mut gap_start: Option<u32> = None;
+    let mut last_seq = begin_seq.saturating_sub(1);
+
+    for (seq, raw) in chunks {
+        last_seq = seq;
+        let decoded = protocol::decode(&raw).ok();
+        let is_admin = decoded
+            .as_ref()
+            .map(|m| is_admin_msg_type(&m.msg_type))
+            .unwrap_or(false);
+
+        if is_admin {
+            gap_start.get_or_insert(seq);
+            continue;
+        }
+
+        if let Some(start) = gap_start.take() {
+            send_gap_fill(write_half, store, sess_key, start, seq, out_seq_num, sender_comp_id, target_comp_id, meters, journal, trace).await;
+        }
+
+        recovered += 1;
+        if let Some(mut m) = decoded {
+            m.set_field(43, "Y");
+            if let Some(orig_sending_time) = m.fields.get(&protocol::TAG_SENDING_TIME).cloned() {
+                m.set_field(122, orig_sending_time);
+            }
+            let msg_type = protocol::msg_type_as_str(&m.msg_type).to_string();
+            let bytes = protocol::encode(m);
+            let _ = write_half.write_all(&bytes).await;
+            journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq), &msg_type, bytes.as_ref()).await;
+            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+            meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+        } else {
+            let _ = write_half.write_all(&raw).await;
+            journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq), "?", raw.as_ref()).await;
+            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+            meters.bytes_out.fetch_add(raw.len() as u64, Ordering::Relaxed);
+        }
+    }
+
+    if let Some(start) = gap_start {
+        send_gap_fill(write_half, store, sess_key, start, last_seq + 1, out_seq_num, sender_comp_id, target_comp_id, meters, journal, trace).await;
+    }
+
+    recovered
+}
+
+/// Applies an inbound SequenceReset to the expected inbound sequence number.
+/// In gap-fill mode (123=Y) the expected sequence only moves forward; in
+/// reset mode it is force-set to NewSeqNo(36) regardless of direction.
+fn apply_sequence_reset(msg: &protocol::FixMessage, expected_in_seq: &mut u32) {
+    if let Some(new_seq) = msg.fields.get(&36).and_then(|s| s.parse::<u32>().ok()) {
+        let gap_fill = msg.fields.get(&123).map(|v| v == "Y").unwrap_or(false);
+        if gap_fill {
+            if new_seq > *expected_in_seq {
+                *expected_in_seq = new_seq;
+            }
+        } else {
+            *expected_in_seq = new_seq;
+        }
+    }
+}
+
+/// Outcome of checking a decoded inbound message's MsgSeqNum(34) against the
+/// expected next inbound sequence number.
+#[derive(Debug)]
+enum SeqCheck {
+    /// In order (or a PossDup): dispatch the message and advance
+    /// `expected_in_seq` past it; a PossDup below `expected_in_seq` leaves
+    /// it where it is.
+    InOrder,
+    /// Higher than expected: the message must be held until the gap is
+    /// filled. Carries the ResendRequest range to ask the peer for.
+    Gap { begin: u32, end: u32 },
+    /// Lower than expected and not a PossDup: an unrecoverable sequence error.
+    TooLow,
+}
+
+/// Classifies an inbound MsgSeqNum(34) relative to the expected next inbound
+/// sequence. Messages carrying PossDupFlag(43=Y) are exempt from gap/too-low
+/// detection since duplicates can legitimately arrive out of the normal flow.
+fn check_inbound_seq(msg: &protocol::FixMessage, expected_in_seq: u32) -> Option<SeqCheck> {
+    let seq_val = msg.fields.get(&34).and_then(|s| s.parse::<u32>().ok())?;
+    let poss_dup = msg.fields.get(&43).map(|v| v == "Y").unwrap_or(false);
+    if poss_dup {
+        return Some(SeqCheck::InOrder);
+    }
+    if seq_val > expected_in_seq {
+        Some(SeqCheck::Gap { begin: expected_in_seq, end: seq_val - 1 })
+    } else if seq_val < expected_in_seq {
+        Some(SeqCheck::TooLow)
+    } else {
+        Some(SeqCheck::InOrder)
+    }
+}
+
+/// Returns `true` if a new session for `key` may be admitted given the
+/// current `active` registry and the configured caps, `false` if it must be
+/// rejected with `DisconnectReason::ConnectionLimit` — either `key` already
+/// has an active session (duplicate logon) or a cap has been reached.
+fn admit_session(
+    active: &HashMap<SessionKey, u64>,
+    key: &SessionKey,
+    max_sessions: Option<usize>,
+    max_sessions_per_comp_id: Option<usize>,
+) -> bool {
+    if active.contains_key(key) {
+        return false;
+    }
+    if let Some(max) = max_sessions {
+        if active.len() >= max {
+            return false;
+        }
+    }
+    if let Some(max) = max_sessions_per_comp_id {
+        let count = active
+            .keys()
+            .filter(|k| k.sender_comp_id == key.sender_comp_id)
+            .count();
+        if count >= max {
+            return false;
+        }
+    }
+    true
+}
+
+/// Computes the exponential backoff delay (with jitter) for reconnect `attempt`
+/// (1-based), capped at `cfg.max_interval`. Jitter is drawn from the same
+/// OS-seeded `RandomState` hasher used for Logon nonces, to avoid pulling in
+/// a `rand` dependency for a single random fraction.
+fn backoff_delay(cfg: &crate::session::ReconnectConfig, attempt: u32) -> Duration {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    let scaled =
+        cfg.initial_interval.as_secs_f64() * cfg.multiplier.powi(attempt.saturating_sub(1) as i32);
+    let capped = scaled.min(cfg.max_interval.as_secs_f64());
+    let r = RandomState::new().build_hasher().finish();
+    let jitter = 0.5 + (r % 1000) as f64 / 2000.0; // in [0.5, 1.0)
+    Duration::from_secs_f64((capped * jitter).max(0.0))
+}
+
+/// Retries `transport.connect` using exponential backoff with jitter, emitting
+/// a `GatewayEvent::Reconnecting` before each attempt. Returns `None` once
+/// `cfg.max_retries` attempts have been exhausted.
+async fn reconnect_with_backoff(
+    transport: &Arc<dyn crate::transport::Transport>,
+    addr: &str,
+    host: &str,
+    session_id: u64,
+    cfg: &crate::session::ReconnectConfig,
+    to_client_tx: &mpsc::Sender<GatewayEvent>,
+    meters: &Arc<SessionMeters>,
+    compression: &[crate::transport::CompressionKind],
+) -> Option<(crate::transport::TransportRead, crate::transport::TransportWrite)> {
+    let mut attempt: u32 = 0;
+    loop {
+        if let Some(max) = cfg.max_retries {
+            if attempt >= max {
+                return None;
+            }
+        }
+        attempt += 1;
+        meters.reconnects.fetch_add(1, Ordering::Relaxed);
+        let delay = backoff_delay(cfg, attempt);
+        let _ = to_client_tx
+            .send(GatewayEvent::Reconnecting {
+                session_id,
+                attempt,
+                delay_ms: delay.as_millis() as u64,
+            })
+            .await;
+        time::sleep(delay).await;
+        if let Ok((read_half, write_half)) = transport.connect(addr, host).await {
+            if let Ok((r, w, _codec)) =
+                crate::transport::negotiate_compression(read_half, write_half, compression).await
+            {
+                return Some((r, w));
+            }
+        }
+    }
+}
+
+/// Runs one initiator connection attempt end-to-end: sends the initial Logon
+/// resuming sequence numbers from `start_out_seq`/`start_in_seq` (or carrying
+/// ResetSeqNumFlag(141=Y) and restarting both at 1 when `send_reset` is set),
+/// then services outbound payloads, heartbeats, and inbound messages until
+/// the peer disconnects or a protocol error occurs. Returns why it stopped so
+/// the caller can decide whether to reconnect.
+#[allow(clippy::too_many_arguments)]
+async fn run_initiator_connection(
+    mut write_half: crate::transport::TransportWrite,
+    mut read_half: crate::transport::TransportRead,
+    app_out_rx: &mut mpsc::Receiver<OutboundPayload>,
+    session_id: u64,
+    sender_comp_id: &str,
+    target_comp_id: &str,
+    heartbeat_interval_secs: u32,
+    store: &Arc<dyn crate::storage::MessageStore>,
+    sess_key: &SessionKey,
+    to_client_tx: &mpsc::Sender<GatewayEvent>,
+    start_out_seq: u32,
+    start_in_seq: u32,
+    send_reset: bool,
+    meters: &Arc<SessionMeters>,
+    transport_kind: TransportKind,
+    journal: &Arc<dyn JournalSink>,
+    trace: &Arc<TraceLevelHandle>,
+    shutdown_rx: &mut watch::Receiver<bool>,
+    begin_string: &str,
+    default_appl_ver_id: Option<&str>,
+) -> DisconnectReason {
+    let hb_interval = Duration::from_secs(heartbeat_interval_secs as u64);
+    let mut out_seq_num: u32 = start_out_seq;
+    let mut in_seq_num: u32 = 0;
+    let mut last_rx: Instant = Instant::now();
+    let mut test_req_outstanding: Option<String> = None;
+    // When `test_req_outstanding` was sent, so the matching Heartbeat echo's
+    // round trip can be timed into `meters.test_request_latency`.
+    let mut test_req_sent_at: Option<Instant> = None;
+    // Next MsgSeqNum(34) we expect from the peer; used for gap detection.
+    let mut expected_in_seq: u32 = start_in_seq;
+    // Messages received ahead of a gap, held until the gap is filled.
+    let mut pending_high: HashMap<u32, Bytes> = HashMap::new();
+    // Set while recovering from a gap we detected (i.e. we sent the
+    // ResendRequest); cleared, with a `GatewayEvent::RecoveryComplete`, once
+    // `expected_in_seq` catches up past it.
+    let mut resend_gap: Option<(u32, u32)> = None;
+    // Application messages received inside `resend_gap` so far, reported as
+    // `recovered` on the `GatewayEvent::RecoveryComplete`.
+    let mut gap_recovered: u32 = 0;
+
+    // Send Logon
+    let mut logon = protocol::build_logon(heartbeat_interval_secs, sender_comp_id, target_comp_id);
+    logon.begin_string = begin_string.to_string();
+    if let Some(appl_ver_id) = default_appl_ver_id {
+        logon.set_field(1137, appl_ver_id);
+    }
+    if send_reset {
+        logon.set_field(141, "Y");
+    }
+    logon.set_field(34, out_seq_num.to_string());
+    let seq_for_store = out_seq_num;
+    out_seq_num += 1;
+    let logon_bytes = protocol::encode(logon);
+    let _ = write_half.write_all(&logon_bytes).await;
+    let _ = store
+        .append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), logon_bytes.as_ref())
+        .await;
+    journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "A", logon_bytes.as_ref()).await;
+    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+    meters.bytes_out.fetch_add(logon_bytes.len() as u64, Ordering::Relaxed);
+
+    // Timers
+    let mut interval = time::interval(hb_interval);
+    interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+    // Frames the wire protocol on BodyLength(9) and validates CheckSum(10) on
+    // decode, replacing manual BytesMut accumulation + `try_extract_one` polling.
+    let mut framed_read = FramedRead::new(read_half, FixCodec);
+
+    loop {
+        tokio::select! {
+            biased;
+            // Application outbound payloads
+            maybe_out = app_out_rx.recv() => {
+                if let Some(payload) = maybe_out {
+                    match payload {
+                        OutboundPayload::Raw(bytes) => {
+                            let _ = write_half.write_all(&bytes).await;
+                            let _ = store.append_bytes(sess_key, Direction::Outbound, None, now_millis(), bytes.as_ref()).await;
+                            let msg_type = protocol::decode(&bytes).map(|m| protocol::msg_type_as_str(&m.msg_type).to_string()).unwrap_or_else(|_| "?".to_string());
+                            journal_record(journal, trace, sess_key, Direction::Outbound, None, &msg_type, bytes.as_ref()).await;
+                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                            meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+                        }
+                        OutboundPayload::Admin(msg) => {
+                            let mut fix = msg.into_fix(sender_comp_id, target_comp_id);
+                            fix.set_field(34, out_seq_num.to_string());
+                            let seq_for_store = out_seq_num;
+                            out_seq_num += 1;
+                            let bytes = protocol::encode(fix);
+                            let _ = write_half.write_all(&bytes).await;
+                            let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), bytes.as_ref()).await;
+                            let msg_type = protocol::decode(&bytes).map(|m| protocol::msg_type_as_str(&m.msg_type).to_string()).unwrap_or_else(|_| "?".to_string());
+                            journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), &msg_type, bytes.as_ref()).await;
+                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                            meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+                        }
+                        OutboundPayload::Flush(ack) => {
+                            let _ = ack.send(());
+                        }
+                    }
+                } else {
+                    return DisconnectReason::ApplicationRequested;
+                }
+            }
+            // Network reads: drain any message already buffered ahead of a gap
+            // before pulling the next frame off the wire.
+            maybe_frame = async {
+                if let Some(b) = pending_high.remove(&expected_in_seq) {
+                    Some(Ok(b))
+                } else {
+                    framed_read.next().await
+                }
+            } => {
+                match maybe_frame {
+                    None => {
+                        let _ = to_client_tx
+                            .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::PeerClosed })
+                            .await;
+                        return DisconnectReason::PeerClosed;
+                    }
+                    Some(Err(_)) => {
+                        // Malformed frame or CheckSum mismatch, rejected by the codec.
+                        let _ = to_client_tx
+                            .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError })
+                            .await;
+                        return DisconnectReason::ProtocolError;
+                    }
+                    Some(Ok(msg_bytes)) => {
+                            last_rx = Instant::now();
+                            match protocol::decode(&msg_bytes) {
+                                Ok(msg) => {
+                                    // Honor a counterparty-initiated reset before gap-checking it.
+                                    if matches!(msg.msg_type, FixMsgType::Logon)
+                                        && msg.fields.get(&141).map(|v| v == "Y").unwrap_or(false)
+                                    {
+                                        let _ = store.reset_sequences(sess_key).await;
+                                        expected_in_seq = 1;
+                                    }
+                                    // Check seqnum if present
+                                    if let Some(seq) = msg.fields.get(&34) {
+                                        if let Ok(seq_val) = seq.parse::<u32>() { in_seq_num = seq_val; }
+                                    }
+                                    match check_inbound_seq(&msg, expected_in_seq) {
+                                        Some(SeqCheck::Gap { begin, end }) => {
+                                            pending_high.insert(in_seq_num, msg_bytes.clone());
+                                            // Already recovering a narrower gap: only ask for the
+                                            // newly-revealed extension instead of re-requesting the
+                                            // whole range, so a burst of further-ahead messages
+                                            // during recovery doesn't re-trigger the same resend.
+                                            let (begin, end) = match resend_gap {
+                                                Some((_, prev_end)) if end <= prev_end => {
+                                                    continue;
+                                                }
+                                                Some((prev_begin, prev_end)) => {
+                                                    resend_gap = Some((prev_begin, end));
+                                                    (prev_end + 1, end)
+                                                }
+                                                None => {
+                                                    resend_gap = Some((begin, end));
+                                                    (begin, end)
+                                                }
+                                            };
+                                            let mut rr = protocol::build_resend_request(begin, end, sender_comp_id, target_comp_id);
+                                            rr.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let rr_bytes = protocol::encode(rr);
+                                            let _ = write_half.write_all(&rr_bytes).await;
+                                            let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), rr_bytes.as_ref()).await;
+                                            journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "2", rr_bytes.as_ref()).await;
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(rr_bytes.len() as u64, Ordering::Relaxed);
+                                            meters.resend_requests_sent.fetch_add(1, Ordering::Relaxed);
+                                            let _ = to_client_tx
+                                                .send(GatewayEvent::ResendInProgress { session_id, from: begin, to: end })
+                                                .await;
+                                            continue;
+                                        }
+                                        Some(SeqCheck::TooLow) => {
+                                            let text = format!(
+                                                "MsgSeqNum too low, expecting {} but received {}",
+                                                expected_in_seq, in_seq_num
+                                            );
+                                            let mut lo = protocol::build_logout(Some(text.as_str()), sender_comp_id, target_comp_id);
+                                            lo.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let lo_bytes = protocol::encode(lo);
+                                            let _ = write_half.write_all(&lo_bytes).await;
+                                            journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "5", lo_bytes.as_ref()).await;
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                            meters.rejects.fetch_add(1, Ordering::Relaxed);
+                                            let _ = to_client_tx
+                                                .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError })
+                                                .await;
+                                            return DisconnectReason::ProtocolError;
+                                        }
+                                        Some(SeqCheck::InOrder) | None => {
+                                            if let Some((begin, end)) = resend_gap {
+                                                if in_seq_num >= expected_in_seq && (begin..=end).contains(&in_seq_num) && !is_admin_msg_type(&msg.msg_type) {
+                                                    gap_recovered += 1;
+                                                }
+                                            }
+                                            expected_in_seq = expected_in_seq.max(in_seq_num + 1);
+                                        }
+                                    }
+                                    if let Some((_, end)) = resend_gap {
+                                        if expected_in_seq > end {
+                                            resend_gap = None;
+                                            let recovered = std::mem::take(&mut gap_recovered);
+                                            let _ = to_client_tx
+                                                .send(GatewayEvent::RecoveryComplete { session_id, recovered })
+                                                .await;
+                                        }
+                                    }
+                                    // Journal inbound
+                                    let inbound_seq = msg.fields.get(&34).and_then(|s| s.parse::<u32>().ok());
+                                    let _ = store.append_bytes(sess_key, Direction::Inbound, inbound_seq, now_millis(), msg_bytes.as_ref()).await;
+                                    journal_record(journal, trace, sess_key, Direction::Inbound, inbound_seq, protocol::msg_type_as_str(&msg.msg_type), msg_bytes.as_ref()).await;
+                                    meters.messages_in.fetch_add(1, Ordering::Relaxed);
+                                    meters.bytes_in.fetch_add(msg_bytes.len() as u64, Ordering::Relaxed);
+
+                                    match msg.msg_type {
+                                        FixMsgType::Logon => {
+                                            // Reject a peer advertising a different application
+                                            // version than this session was configured to speak.
+                                            // Mirrors the `AuthOutcome::Rejected` handling on the
+                                            // acceptor side: Logout, then disconnect, rather than
+                                            // silently parsing its messages against the wrong layout.
+                                            let peer_appl_ver_id = msg.fields.get(&1137).map(|s| s.as_str());
+                                            if let Some(expected) = default_appl_ver_id {
+                                                if peer_appl_ver_id != Some(expected) {
+                                                    let text = format!(
+                                                        "DefaultApplVerID mismatch: expected {}, got {}",
+                                                        expected,
+                                                        peer_appl_ver_id.unwrap_or("<none>")
+                                                    );
+                                                    let mut lo = protocol::build_logout(Some(text.as_str()), sender_comp_id, target_comp_id);
+                                                    lo.set_field(34, out_seq_num.to_string());
+                                                    let seq_for_store = out_seq_num;
+                                                    out_seq_num += 1;
+                                                    let lo_bytes = protocol::encode(lo);
+                                                    let _ = write_half.write_all(&lo_bytes).await;
+                                                    let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), lo_bytes.as_ref()).await;
+                                                    journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "5", lo_bytes.as_ref()).await;
+                                                    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                                    meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                                    meters.rejects.fetch_add(1, Ordering::Relaxed);
+                                                    let _ = to_client_tx
+                                                        .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::AuthenticationFailed })
+                                                        .await;
+                                                    return DisconnectReason::AuthenticationFailed;
+                                                }
+                                            }
+                                            let _ = to_client_tx
+                                                .send(GatewayEvent::SessionActive {
+                                                    session_id,
+                                                    identity: target_comp_id.to_string(),
+                                                    transport: transport_kind,
+                                                })
+                                                .await;
+                                        }
+                                        FixMsgType::Heartbeat => {
+                                            if let Some(id) = msg.fields.get(&112) {
+                                                if test_req_outstanding.as_deref() == Some(id) {
+                                                    test_req_outstanding = None;
+                                                    if let Some(sent_at) = test_req_sent_at.take() {
+                                                        meters.test_request_latency.record(sent_at.elapsed().as_nanos() as u64);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        FixMsgType::TestRequest => {
+                                            let tr_id = msg.fields.get(&112).cloned();
+                                            let mut hb = protocol::build_heartbeat(tr_id.as_deref(), sender_comp_id, target_comp_id);
+                                            hb.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let hb_bytes = protocol::encode(hb);
+                                            let _ = write_half.write_all(&hb_bytes).await;
+                                            let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), hb_bytes.as_ref()).await;
+                                            journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "0", hb_bytes.as_ref()).await;
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(hb_bytes.len() as u64, Ordering::Relaxed);
+                                            meters.heartbeats_sent.fetch_add(1, Ordering::Relaxed);
+                                        }
+                                        FixMsgType::ResendRequest => {
+                                            let begin = msg.fields.get(&7).and_then(|s| s.parse::<u32>().ok()).unwrap_or(1);
+                                            let end_raw = msg.fields.get(&16).and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
+                                            let highest_sent = out_seq_num.saturating_sub(1);
+                                            // Clamp to what we've actually sent: an EndSeqNo near u32::MAX
+                                            // (0 means "everything up to now") must never reach
+                                            // `replay_resend_range`, since it derives NewSeqNo as `end + 1`
+                                            // for an all-gap-fill reply and that addition would overflow.
+                                            let end = if end_raw == 0 { highest_sent } else { end_raw.min(highest_sent) };
+                                            let recovered = replay_resend_range(&mut write_half, store, sess_key, begin, end, &mut out_seq_num, sender_comp_id, target_comp_id, meters, journal, trace).await;
+                                            if recovered > 0 {
+                                                let _ = to_client_tx
+                                                    .send(GatewayEvent::RecoveryComplete { session_id, recovered })
+                                                    .await;
+                                            }
+                                        }
+                                        FixMsgType::SequenceReset => {
+                                            apply_sequence_reset(&msg, &mut expected_in_seq);
+                                        }
+                                        FixMsgType::Unknown(_) => {}
+                                    }
+                                    // Forward inbound to client as event
+                                    let msg_type = match msg.msg_type { FixMsgType::Unknown(_) => "?".to_string(), _ => protocol::msg_type_as_str(&msg.msg_type).to_string() };
+                                    let _ = to_client_tx
+                                        .send(GatewayEvent::InboundMessage { session_id, msg_type, payload: msg_bytes.clone() })
+                                        .await;
+                                }
+                                Err(_) => {
+                                    let _ = to_client_tx
+                                        .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError })
+                                        .await;
+                                    return DisconnectReason::ProtocolError;
+                                }
+                            }
+                        }
+                    }
+                }
+            // Heartbeat timers
+            _ = interval.tick() => {
+                let idle = last_rx.elapsed();
+                if idle >= hb_interval * 3 {
+                    // If we already sent a TestRequest (at the `hb_interval *
+                    // 2` threshold below) and it's still outstanding, the peer
+                    // failed to answer the liveness probe specifically;
+                    // otherwise this is plain inactivity.
+                    let reason = if test_req_outstanding.is_some() {
+                        DisconnectReason::HeartbeatTimeout
+                    } else {
+                        DisconnectReason::Timeout
+                    };
+                    let _ = to_client_tx
+                        .send(GatewayEvent::Disconnected { session_id, reason })
+                        .await;
+                    return reason;
+                } else if idle >= hb_interval * 2 {
+                    if test_req_outstanding.is_none() {
+                        let _ = to_client_tx.send(GatewayEvent::HeartbeatTimeout { session_id }).await;
+                        let tr_id = format!("TR-{}", out_seq_num);
+                        let mut tr = protocol::build_test_request(&tr_id, sender_comp_id, target_comp_id);
+                        tr.set_field(34, out_seq_num.to_string());
+                        let seq_for_store = out_seq_num;
+                        out_seq_num += 1;
+                        let tr_bytes = protocol::encode(tr);
+                        let _ = write_half.write_all(&tr_bytes).await;
+                        let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), tr_bytes.as_ref()).await;
+                        journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "1", tr_bytes.as_ref()).await;
+                        meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                        meters.bytes_out.fetch_add(tr_bytes.len() as u64, Ordering::Relaxed);
+                        meters.test_requests_sent.fetch_add(1, Ordering::Relaxed);
+                        test_req_outstanding = Some(tr_id);
+                        test_req_sent_at = Some(Instant::now());
+                    }
+                } else if idle >= hb_interval {
+                    let mut hb = protocol::build_heartbeat(None, sender_comp_id, target_comp_id);
+                    hb.set_field(34, out_seq_num.to_string());
+                    let seq_for_store = out_seq_num;
+                    out_seq_num += 1;
+                    let hb_bytes = protocol::encode(hb);
+                    let _ = write_half.write_all(&hb_bytes).await;
+                    let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), hb_bytes.as_ref()).await;
+                    journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "0", hb_bytes.as_ref()).await;
+                    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                    meters.bytes_out.fetch_add(hb_bytes.len() as u64, Ordering::Relaxed);
+                    meters.heartbeats_sent.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            // Gateway-wide graceful shutdown: say goodbye and let the caller
+            // decide whether to reconnect (it won't, since the signal stays
+            // set for the lifetime of the gateway).
+            _ = shutdown_rx.changed() => {
+                let mut lo = protocol::build_logout(Some("gateway shutting down"), sender_comp_id, target_comp_id);
+                lo.set_field(34, out_seq_num.to_string());
+                let seq_for_store = out_seq_num;
+                out_seq_num += 1;
+                let lo_bytes = protocol::encode(lo);
+                let _ = write_half.write_all(&lo_bytes).await;
+                let _ = store.append_bytes(sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), lo_bytes.as_ref()).await;
+                journal_record(journal, trace, sess_key, Direction::Outbound, Some(seq_for_store), "5", lo_bytes.as_ref()).await;
+                meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                let _ = to_client_tx
+                    .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::Shutdown })
+                    .await;
+                return DisconnectReason::Shutdown;
+            }
+        }
+    }
+}
+
+/// Runs one accepted (inbound) connection end-to-end: performs the Logon
+/// handshake (honoring ResetSeqNumFlag(141=Y), admission control, and the
+/// pluggable `AuthStrategy`, additionally checking `expected_sessions` as a
+/// sender/target CompID allowlist if set), then services outbound payloads,
+/// heartbeats, and inbound messages until the peer disconnects or a protocol
+/// error occurs. `GatewayEvent`s are broadcast to every sender in `clients`.
+///
+/// Shared by the gateway's always-on startup acceptor and any acceptors
+/// started on demand via `GatewayCommand::StartAcceptor`/`ClientCommand::Listen`.
+#[allow(clippy::too_many_arguments)]
+async fn run_acceptor_connection(
+    mut write_half: TransportWrite,
+    read_half: crate::transport::TransportRead,
+    mut app_out_rx: mpsc::Receiver<OutboundPayload>,
+    session_id: u64,
+    clients: Arc<RwLock<Vec<mpsc::Sender<GatewayEvent>>>>,
+    active_sessions: Arc<RwLock<HashMap<SessionKey, u64>>>,
+    auth: Arc<dyn crate::config::AuthStrategy>,
+    store: Arc<dyn crate::storage::MessageStore>,
+    max_sessions: Option<usize>,
+    max_sessions_per_comp_id: Option<usize>,
+    expected_sessions: Option<Arc<Vec<(String, String)>>>,
+    meters: Arc<SessionMeters>,
+    transport_kind: TransportKind,
+    journal: Arc<dyn JournalSink>,
+    trace: Arc<TraceLevelHandle>,
+    session_keys: Arc<RwLock<HashMap<u64, SessionKey>>>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) {
+    let mut out_seq_num: u32 = 1;
+    let mut in_seq_num: u32 = 0;
+    let mut last_rx: Instant = Instant::now();
+    let mut test_req_outstanding: Option<String> = None;
+    let mut hb_interval = Duration::from_secs(30);
+    let mut sender_comp = String::new();
+    let mut target_comp = String::new();
+    // Per-session nonce for the Logon challenge/response handshake; auth
+    // strategies that verify a digest of it see it via `LogonRequest::nonce`.
+    let nonce = generate_nonce(session_id);
+    // Identifies this session for the outbound journal; known once Logon
+    // has been processed and our/their CompIDs are established.
+    let mut sess_key: Option<SessionKey> = None;
+    // Next MsgSeqNum(34) we expect from the peer; used for gap detection.
+    let mut expected_in_seq: u32 = 1;
+    // Messages received ahead of a gap, held until the gap is filled.
+    let mut pending_high: HashMap<u32, Bytes> = HashMap::new();
+    // Set while recovering from a gap we detected (i.e. we sent the
+    // ResendRequest); cleared, with a `GatewayEvent::RecoveryComplete`, once
+    // `expected_in_seq` catches up past it.
+    let mut resend_gap: Option<(u32, u32)> = None;
+    // Application messages received inside `resend_gap` so far, reported as
+    // `recovered` on the `GatewayEvent::RecoveryComplete`.
+    let mut gap_recovered: u32 = 0;
+    // Frames the wire protocol on BodyLength(9) and validates CheckSum(10) on decode.
+    let mut framed_read = FramedRead::new(read_half, FixCodec);
+
+    let mut tick = time::interval(Duration::from_secs(1));
+    tick.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            biased;
+            maybe_out = app_out_rx.recv() => {
+                if let Some(payload) = maybe_out {
+                    match payload {
+                        OutboundPayload::Raw(bytes) => {
+                            let _ = write_half.write_all(&bytes).await;
+                            if let Some(ref key) = sess_key {
+                                let _ = store.append_bytes(key, Direction::Outbound, None, now_millis(), bytes.as_ref()).await;
+                                let msg_type = protocol::decode(&bytes).map(|m| protocol::msg_type_as_str(&m.msg_type).to_string()).unwrap_or_else(|_| "?".to_string());
+                                journal_record(&journal, &trace, key, Direction::Outbound, None, &msg_type, bytes.as_ref()).await;
+                            }
+                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                            meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+                        }
+                        OutboundPayload::Admin(msg) => {
+                            let mut fix = msg.into_fix(&target_comp, &sender_comp);
+                            fix.set_field(34, out_seq_num.to_string());
+                            let seq_for_store = out_seq_num;
+                            out_seq_num += 1;
+                            let bytes = protocol::encode(fix);
+                            let _ = write_half.write_all(&bytes).await;
+                            if let Some(ref key) = sess_key {
+                                let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), bytes.as_ref()).await;
+                                let msg_type = protocol::decode(&bytes).map(|m| protocol::msg_type_as_str(&m.msg_type).to_string()).unwrap_or_else(|_| "?".to_string());
+                                journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), &msg_type, bytes.as_ref()).await;
+                            }
+                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                            meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+                        }
+                        OutboundPayload::Flush(ack) => {
+                            let _ = ack.send(());
+                        }
+                    }
+                } else { break; }
+            }
+            maybe_frame = async {
+                if let Some(b) = pending_high.remove(&expected_in_seq) {
+                    Some(Ok(b))
+                } else {
+                    framed_read.next().await
+                }
+            } => {
+                match maybe_frame {
+                    None => {
+                        let senders = clients.read().await;
+                        for tx in senders.iter() {
+                            let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::PeerClosed }).await;
+                        }
+                        break;
+                    }
+                    Some(Err(_)) => {
+                        let senders = clients.read().await;
+                        for tx in senders.iter() {
+                            let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError }).await;
+                        }
+                        break;
+                    }
+                    Some(Ok(msg_bytes)) => {
+                            last_rx = Instant::now();
+                            match protocol::decode(&msg_bytes) {
+                                Ok(msg) => {
+                                    // On the first Logon, resume sequence counters from `store`
+                                    // (or restart at 1 if ResetSeqNumFlag(141=Y) is set) before
+                                    // running gap detection against it.
+                                    if sess_key.is_none() {
+                                        if let FixMsgType::Logon = msg.msg_type {
+                                            if let (Some(s), Some(t)) = (msg.fields.get(&49), msg.fields.get(&56)) {
+                                                sender_comp = s.clone();
+                                                target_comp = t.clone();
+                                                if let Some(ref table) = expected_sessions {
+                                                    let permitted = table.iter().any(|(s, t)| s == &sender_comp && t == &target_comp);
+                                                    if !permitted {
+                                                        let mut lo = protocol::build_logout(Some("sender/target comp-id pair not permitted"), &target_comp, &sender_comp);
+                                                        lo.set_field(34, out_seq_num.to_string());
+                                                        out_seq_num += 1;
+                                                        let lo_bytes = protocol::encode(lo);
+                                                        let _ = write_half.write_all(&lo_bytes).await;
+                                                        meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                                        meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                                        meters.rejects.fetch_add(1, Ordering::Relaxed);
+                                                        let senders = clients.read().await;
+                                                        for tx in senders.iter() {
+                                                            let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::AuthenticationFailed }).await;
+                                                        }
+                                                        break;
+                                                    }
+                                                }
+                                                let key = SessionKey {
+                                                    sender_comp_id: target_comp.clone(),
+                                                    target_comp_id: sender_comp.clone(),
+                                                };
+                                                {
+                                                    let mut sessions = active_sessions.write().await;
+                                                    if !admit_session(&sessions, &key, max_sessions, max_sessions_per_comp_id) {
+                                                        drop(sessions);
+                                                        let mut lo = protocol::build_logout(Some("connection limit reached"), &target_comp, &sender_comp);
+                                                        lo.set_field(34, out_seq_num.to_string());
+                                                        out_seq_num += 1;
+                                                        let lo_bytes = protocol::encode(lo);
+                                                        let _ = write_half.write_all(&lo_bytes).await;
+                                                        meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                                        meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                                        meters.rejects.fetch_add(1, Ordering::Relaxed);
+                                                        let senders = clients.read().await;
+                                                        for tx in senders.iter() {
+                                                            let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ConnectionLimit }).await;
+                                                        }
+                                                        break;
+                                                    }
+                                                    sessions.insert(key.clone(), session_id);
+                                                }
+                                                let reset_requested = msg.fields.get(&141).map(|v| v == "Y").unwrap_or(false);
+                                                if reset_requested {
+                                                    let _ = store.reset_sequences(&key).await;
+                                                    expected_in_seq = 1;
+                                                    out_seq_num = 1;
+                                                } else {
+                                                    expected_in_seq = store.last_inbound_seq(&key).await.ok().flatten().map(|s| s + 1).unwrap_or(1);
+                                                    out_seq_num = store.last_outbound_seq(&key).await.ok().flatten().map(|s| s + 1).unwrap_or(1);
+                                                }
+                                                session_keys.write().await.insert(session_id, key.clone());
+                                                sess_key = Some(key);
+                                            }
+                                        }
+                                    }
+                                    if let Some(seq) = msg.fields.get(&34) {
+                                        if let Ok(seq_val) = seq.parse::<u32>() { in_seq_num = seq_val; }
+                                    }
+                                    match check_inbound_seq(&msg, expected_in_seq) {
+                                        Some(SeqCheck::Gap { begin, end }) => {
+                                            pending_high.insert(in_seq_num, msg_bytes.clone());
+                                            // Already recovering a narrower gap: only ask for the
+                                            // newly-revealed extension instead of re-requesting the
+                                            // whole range, so a burst of further-ahead messages
+                                            // during recovery doesn't re-trigger the same resend.
+                                            let (begin, end) = match resend_gap {
+                                                Some((_, prev_end)) if end <= prev_end => {
+                                                    continue;
+                                                }
+                                                Some((prev_begin, prev_end)) => {
+                                                    resend_gap = Some((prev_begin, end));
+                                                    (prev_end + 1, end)
+                                                }
+                                                None => {
+                                                    resend_gap = Some((begin, end));
+                                                    (begin, end)
+                                                }
+                                            };
+                                            if let Some(ref key) = sess_key {
+                                                let mut rr = protocol::build_resend_request(begin, end, &target_comp, &sender_comp);
+                                                rr.set_field(34, out_seq_num.to_string());
+                                                let seq_for_store = out_seq_num;
+                                                out_seq_num += 1;
+                                                let rr_bytes = protocol::encode(rr);
+                                                let _ = write_half.write_all(&rr_bytes).await;
+                                                let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), rr_bytes.as_ref()).await;
+                                                journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "2", rr_bytes.as_ref()).await;
+                                                meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                                meters.bytes_out.fetch_add(rr_bytes.len() as u64, Ordering::Relaxed);
+                                                meters.resend_requests_sent.fetch_add(1, Ordering::Relaxed);
+                                            }
+                                            let senders = clients.read().await;
+                                            for tx in senders.iter() {
+                                                let _ = tx.send(GatewayEvent::ResendInProgress { session_id, from: begin, to: end }).await;
+                                            }
+                                            continue;
+                                        }
+                                        Some(SeqCheck::TooLow) => {
+                                            let text = format!(
+                                                "MsgSeqNum too low, expecting {} but received {}",
+                                                expected_in_seq, in_seq_num
+                                            );
+                                            let mut lo = protocol::build_logout(Some(text.as_str()), &target_comp, &sender_comp);
+                                            lo.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let lo_bytes = protocol::encode(lo);
+                                            let _ = write_half.write_all(&lo_bytes).await;
+                                            if let Some(ref key) = sess_key {
+                                                journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "5", lo_bytes.as_ref()).await;
+                                            }
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                            meters.rejects.fetch_add(1, Ordering::Relaxed);
+                                            let senders = clients.read().await;
+                                            for tx in senders.iter() {
+                                                let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError }).await;
+                                            }
+                                            break;
+                                        }
+                                        Some(SeqCheck::InOrder) | None => {
+                                            if let Some((begin, end)) = resend_gap {
+                                                if in_seq_num >= expected_in_seq && (begin..=end).contains(&in_seq_num) && !is_admin_msg_type(&msg.msg_type) {
+                                                    gap_recovered += 1;
+                                                }
+                                            }
+                                            expected_in_seq = expected_in_seq.max(in_seq_num + 1);
+                                        }
+                                    }
+                                    if let Some((_, end)) = resend_gap {
+                                        if expected_in_seq > end {
+                                            resend_gap = None;
+                                            let recovered = std::mem::take(&mut gap_recovered);
+                                            let senders = clients.read().await;
+                                            for tx in senders.iter() {
+                                                let _ = tx.send(GatewayEvent::RecoveryComplete { session_id, recovered }).await;
+                                            }
+                                        }
+                                    }
+                                    match msg.msg_type {
+                                        FixMsgType::Logon => {
+                                            if let Some(hb) = msg.fields.get(&108) {
+                                                if let Ok(secs) = hb.parse::<u64>() { hb_interval = Duration::from_secs(secs); }
+                                            }
+
+                                            // Validate using pluggable auth, including any challenge/response
+                                            // credentials (Username(553)/Password(554)/RawData(96)).
+                                            let logon_request = LogonRequest {
+                                                sender_comp_id: sender_comp.clone(),
+                                                target_comp_id: target_comp.clone(),
+                                                username: msg.fields.get(&553).cloned(),
+                                                password: msg.fields.get(&554).cloned(),
+                                                raw_data: msg.fields.get(&96).cloned(),
+                                                nonce: nonce.clone(),
+                                                heartbeat_interval_secs: hb_interval.as_secs() as u32,
+                                                encrypt_method: msg.fields.get(&98).cloned(),
+                                                reset_seq_num: msg.fields.get(&141).map(|v| v == "Y").unwrap_or(false),
+                                            };
+                                            let identity = match auth.authenticate(&logon_request).await {
+                                                AuthOutcome::Accepted { identity } => identity,
+                                                AuthOutcome::AcceptWith { identity, reset_seq, heartbeat_override } => {
+                                                    if let Some(secs) = heartbeat_override {
+                                                        hb_interval = Duration::from_secs(secs as u64);
+                                                    }
+                                                    if reset_seq {
+                                                        if let Some(ref key) = sess_key {
+                                                            let _ = store.reset_sequences(key).await;
+                                                        }
+                                                        expected_in_seq = 1;
+                                                        out_seq_num = 1;
+                                                    }
+                                                    identity
+                                                }
+                                                AuthOutcome::Rejected { reason } => {
+                                                    let mut lo = protocol::build_logout(Some(reason.as_str()), &target_comp, &sender_comp);
+                                                    lo.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
+                                                    let lo_bytes = protocol::encode(lo);
+                                                    let _ = write_half.write_all(&lo_bytes).await;
+                                                    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                                    meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                                    meters.rejects.fetch_add(1, Ordering::Relaxed);
+                                                    let senders = clients.read().await;
+                                                    for tx in senders.iter() {
+                                                        let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::AuthenticationFailed }).await;
+                                                    }
+                                                    break;
+                                                }
+                                            };
+
+                                            // Echo logon
+                                            let mut logon = protocol::build_logon(hb_interval.as_secs() as u32, &target_comp, &sender_comp);
+                                            logon.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let bytes = protocol::encode(logon);
+                                            let _ = write_half.write_all(&bytes).await;
+                                            if let Some(ref key) = sess_key {
+                                                let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), bytes.as_ref()).await;
+                                                journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "A", bytes.as_ref()).await;
+                                            }
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+
+                                            let senders = clients.read().await;
+                                            for tx in senders.iter() {
+                                                let _ = tx.send(GatewayEvent::SessionActive {
+                                                    session_id,
+                                                    identity: identity.clone(),
+                                                    transport: transport_kind,
+                                                }).await;
+                                            }
+                                        }
+                                        FixMsgType::TestRequest => {
+                                            let id = msg.fields.get(&112).cloned();
+                                            let mut hb = protocol::build_heartbeat(id.as_deref(), &target_comp, &sender_comp);
+                                            hb.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let hb_bytes = protocol::encode(hb);
+                                            let _ = write_half.write_all(&hb_bytes).await;
+                                            if let Some(ref key) = sess_key {
+                                                let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), hb_bytes.as_ref()).await;
+                                                journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "0", hb_bytes.as_ref()).await;
+                                            }
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(hb_bytes.len() as u64, Ordering::Relaxed);
+                                            meters.heartbeats_sent.fetch_add(1, Ordering::Relaxed);
+                                        }
+                                        FixMsgType::Logout => {
+                                            let mut lo = protocol::build_logout(None, &target_comp, &sender_comp);
+                                            lo.set_field(34, out_seq_num.to_string());
+                                            let seq_for_store = out_seq_num;
+                                            out_seq_num += 1;
+                                            let lo_bytes = protocol::encode(lo);
+                                            let _ = write_half.write_all(&lo_bytes).await;
+                                            if let Some(ref key) = sess_key {
+                                                let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), lo_bytes.as_ref()).await;
+                                                journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "5", lo_bytes.as_ref()).await;
+                                            }
+                                            meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                                            meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                                            let senders = clients.read().await;
+                                            for tx in senders.iter() {
+                                                let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ApplicationRequested }).await;
+                                            }
+                                            break;
+                                        }
+                                        FixMsgType::ResendRequest => {
+                                            let begin = msg.fields.get(&7).and_then(|s| s.parse::<u32>().ok()).unwrap_or(1);
+                                            let end_raw = msg.fields.get(&16).and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
+                                            let highest_sent = out_seq_num.saturating_sub(1);
+                                            // Clamp to what we've actually sent: an EndSeqNo near u32::MAX
+                                            // (0 means "everything up to now") must never reach
+                                            // `replay_resend_range`, since it derives NewSeqNo as `end + 1`
+                                            // for an all-gap-fill reply and that addition would overflow.
+                                            let end = if end_raw == 0 { highest_sent } else { end_raw.min(highest_sent) };
+                                            if let Some(ref key) = sess_key {
+                                                let recovered = replay_resend_range(&mut write_half, &store, key, begin, end, &mut out_seq_num, &target_comp, &sender_comp, &meters, &journal, &trace).await;
+                                                if recovered > 0 {
+                                                    let senders = clients.read().await;
+                                                    for tx in senders.iter() {
+                                                        let _ = tx.send(GatewayEvent::RecoveryComplete { session_id, recovered }).await;
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        FixMsgType::SequenceReset => {
+                                            apply_sequence_reset(&msg, &mut expected_in_seq);
+                                        }
+                                        FixMsgType::Heartbeat => {
+                                            if let Some(id) = msg.fields.get(&112) {
+                                                if test_req_outstanding.as_deref() == Some(id.as_str()) {
+                                                    test_req_outstanding = None;
+                                                }
+                                            }
+                                        }
+                                        FixMsgType::Unknown(_) => {}
+                                    }
+                                    if let Some(ref key) = sess_key {
+                                        let _ = store.append_bytes(key, Direction::Inbound, Some(in_seq_num), now_millis(), msg_bytes.as_ref()).await;
+                                        journal_record(&journal, &trace, key, Direction::Inbound, Some(in_seq_num), protocol::msg_type_as_str(&msg.msg_type), msg_bytes.as_ref()).await;
+                                    }
+                                    meters.messages_in.fetch_add(1, Ordering::Relaxed);
+                                    meters.bytes_in.fetch_add(msg_bytes.len() as u64, Ordering::Relaxed);
+                                    let msg_type = match msg.msg_type { FixMsgType::Unknown(_) => "?".to_string(), _ => protocol::msg_type_as_str(&msg.msg_type).to_string() };
+                                    let senders = clients.read().await;
+                                    for tx in senders.iter() {
+                                        let _ = tx.send(GatewayEvent::InboundMessage { session_id, msg_type: msg_type.clone(), payload: msg_bytes.clone() }).await;
+                                    }
+                                }
+                                Err(_) => {
+                                    let senders = clients.read().await;
+                                    for tx in senders.iter() {
+                                        let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError }).await;
+                                    }
+                                    break;
+                                }
+                            }
+                    }
+                }
+            }
+            _ = tick.tick() => {
+                let idle = last_rx.elapsed();
+                if idle >= hb_interval * 3 {
+                    // If a TestRequest (sent at the `hb_interval * 2` threshold
+                    // below) is still outstanding, the peer failed to answer
+                    // the liveness probe specifically; otherwise this is plain
+                    // inactivity.
+                    let reason = if test_req_outstanding.is_some() {
+                        DisconnectReason::HeartbeatTimeout
+                    } else {
+                        DisconnectReason::Timeout
+                    };
+                    let senders = clients.read().await;
+                    for tx in senders.iter() {
+                        let _ = tx.send(GatewayEvent::Disconnected { session_id, reason }).await;
+                    }
+                    break;
+                } else if idle >= hb_interval * 2 {
+                    if test_req_outstanding.is_none() {
+                        {
+                            let senders = clients.read().await;
+                            for tx in senders.iter() {
+                                let _ = tx.send(GatewayEvent::HeartbeatTimeout { session_id }).await;
+                            }
+                        }
+                        let tr_id = format!("TR-{}", out_seq_num);
+                        let mut tr = protocol::build_test_request(&tr_id, &target_comp, &sender_comp);
+                        tr.set_field(34, out_seq_num.to_string());
+                        let seq_for_store = out_seq_num;
+                        out_seq_num += 1;
+                        let tr_bytes = protocol::encode(tr);
+                        let _ = write_half.write_all(&tr_bytes).await;
+                        if let Some(ref key) = sess_key {
+                            let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), tr_bytes.as_ref()).await;
+                            journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "1", tr_bytes.as_ref()).await;
+                        }
+                        meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                        meters.bytes_out.fetch_add(tr_bytes.len() as u64, Ordering::Relaxed);
+                        meters.test_requests_sent.fetch_add(1, Ordering::Relaxed);
+                        test_req_outstanding = Some(tr_id);
+                    }
+                } else if idle >= hb_interval {
+                    let mut hb = protocol::build_heartbeat(None, &target_comp, &sender_comp);
+                    hb.set_field(34, out_seq_num.to_string());
+                    let seq_for_store = out_seq_num;
+                    out_seq_num += 1;
+                    let hb_bytes = protocol::encode(hb);
+                    let _ = write_half.write_all(&hb_bytes).await;
+                    if let Some(ref key) = sess_key {
+                        let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), hb_bytes.as_ref()).await;
+                        journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "0", hb_bytes.as_ref()).await;
+                    }
+                    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                    meters.bytes_out.fetch_add(hb_bytes.len() as u64, Ordering::Relaxed);
+                    meters.heartbeats_sent.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            // Gateway-wide graceful shutdown: only a session that's completed
+            // its Logon (i.e. has a `sess_key`) gets a Logout, since anything
+            // earlier has no established sender/target CompIDs to address one to.
+            _ = shutdown_rx.changed() => {
+                if let Some(ref key) = sess_key {
+                    let mut lo = protocol::build_logout(Some("gateway shutting down"), &target_comp, &sender_comp);
+                    lo.set_field(34, out_seq_num.to_string());
+                    let seq_for_store = out_seq_num;
+                    out_seq_num += 1;
+                    let lo_bytes = protocol::encode(lo);
+                    let _ = write_half.write_all(&lo_bytes).await;
+                    let _ = store.append_bytes(key, Direction::Outbound, Some(seq_for_store), now_millis(), lo_bytes.as_ref()).await;
+                    journal_record(&journal, &trace, key, Direction::Outbound, Some(seq_for_store), "5", lo_bytes.as_ref()).await;
+                    meters.messages_out.fetch_add(1, Ordering::Relaxed);
+                    meters.bytes_out.fetch_add(lo_bytes.len() as u64, Ordering::Relaxed);
+                }
+                let senders = clients.read().await;
+                for tx in senders.iter() {
+                    let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::Shutdown }).await;
+                }
+                break;
+            }
+        }
+    }
+    if let Some(ref key) = sess_key {
+        active_sessions.write().await.remove(key);
+    }
+    session_keys.write().await.remove(&session_id);
+    let senders = clients.read().await;
+    for tx in senders.iter() {
+        let _ = tx.send(GatewayEvent::SessionTerminated { session_id }).await;
+    }
+}
+
+/// Runs the accept loop for one bound `listener`: on each inbound connection,
+/// registers its outbound sender in `global_session_senders` (and, if given,
+/// `client_session_senders` — used by `ClientCommand::Listen` so a single
+/// client owns both its initiated and accepted sessions) and spawns
+/// [`run_acceptor_connection`] to drive the session, broadcasting events to
+/// every sender in `clients`. Shared by the gateway's always-on startup
+/// acceptor and any acceptors started on demand via
+/// `GatewayCommand::StartAcceptor`/`ClientCommand::Listen`.
+#[allow(clippy::too_many_arguments)]
+async fn run_accept_loop(
+    listener: crate::transport::GatewayListener,
+    transport: Arc<dyn crate::transport::Transport>,
+    next_session_id: Arc<AtomicU64>,
+    clients: Arc<RwLock<Vec<mpsc::Sender<GatewayEvent>>>>,
+    global_session_senders: Arc<RwLock<HashMap<u64, mpsc::Sender<OutboundPayload>>>>,
+    client_session_senders: Option<Arc<RwLock<HashMap<u64, mpsc::Sender<OutboundPayload>>>>>,
+    active_sessions: Arc<RwLock<HashMap<SessionKey, u64>>>,
+    auth: Arc<dyn crate::config::AuthStrategy>,
+    store: Arc<dyn crate::storage::MessageStore>,
+    max_sessions: Option<usize>,
+    max_sessions_per_comp_id: Option<usize>,
+    expected_sessions: Option<Arc<Vec<(String, String)>>>,
+    meters: Arc<RwLock<HashMap<u64, Arc<SessionMeters>>>>,
+    journal: Arc<dyn JournalSink>,
+    trace_levels: Arc<RwLock<HashMap<u64, Arc<TraceLevelHandle>>>>,
+    session_keys: Arc<RwLock<HashMap<u64, SessionKey>>>,
+    mut shutdown_rx: watch::Receiver<bool>,
+    compression: Vec<crate::transport::CompressionKind>,
+) {
+    let transport_kind = transport.kind();
+    loop {
+        let accepted = tokio::select! {
+            biased;
+            // Stop accepting new connections once a graceful shutdown is
+            // triggered; live sessions are drained by their own connection
+            // tasks, each watching the same signal.
+            _ = shutdown_rx.changed() => break,
+            accepted = transport.accept(&listener) => accepted,
+        };
+        let accepted = match accepted {
+            Ok((read_half, write_half)) => {
+                crate::transport::negotiate_compression(read_half, write_half, &compression)
+                    .await
+                    .map(|(r, w, _codec)| (r, w))
+            }
+            Err(e) => Err(e),
+        };
+        match accepted {
+            Ok((read_half, write_half)) => {
+                let session_id = next_session_id.fetch_add(1, Ordering::Relaxed) + 1;
+                let session_meters = Arc::new(SessionMeters::default());
+                meters.write().await.insert(session_id, Arc::clone(&session_meters));
+                let session_trace = Arc::new(TraceLevelHandle::default());
+                trace_levels.write().await.insert(session_id, Arc::clone(&session_trace));
+                let (app_out_tx, app_out_rx) = mpsc::channel::<OutboundPayload>(1024);
+                {
+                    let mut map = global_session_senders.write().await;
+                    map.insert(session_id, app_out_tx.clone());
+                }
+                if let Some(ref csenders) = client_session_senders {
+                    csenders.write().await.insert(session_id, app_out_tx.clone());
+                }
+                tokio::spawn(run_acceptor_connection(
+                    write_half,
+                    read_half,
+                    app_out_rx,
+                    session_id,
+                    Arc::clone(&clients),
+                    Arc::clone(&active_sessions),
+                    Arc::clone(&auth),
+                    store.clone(),
+                    max_sessions,
+                    max_sessions_per_comp_id,
+                    expected_sessions.clone(),
+                    session_meters,
+                    transport_kind,
+                    Arc::clone(&journal),
+                    session_trace,
+                    Arc::clone(&session_keys),
+                    shutdown_rx.clone(),
+                ));
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Accept failed");
+            }
+        }
+    }
+}
+
 /// Handle for communicating with a running FIX gateway.
 ///
 /// Provides a thread-safe interface for clients to register with
@@ -47,6 +1415,116 @@ impl GatewayHandle {
             .map_err(|_| FixgError::ChannelClosed)?;
         rx.await.map_err(|_| FixgError::ChannelClosed)
     }
+
+    /// Starts a gateway-wide acceptor on `bind_addr`, permitting only the
+    /// given `(sender_comp_id, target_comp_id)` pairs. Accepted sessions
+    /// surface the same `GatewayEvent::SessionActive`/`InboundMessage`/
+    /// `Disconnected` stream as initiated ones to every registered client.
+    pub async fn start_acceptor(
+        &self,
+        bind_addr: SocketAddr,
+        expected_sessions: Vec<(String, String)>,
+    ) -> Result<()> {
+        self.start_acceptor_with_transport(bind_addr, expected_sessions, None)
+            .await
+    }
+
+    /// Like [`start_acceptor`](Self::start_acceptor), but overrides the
+    /// gateway's configured transport for this acceptor only.
+    pub async fn start_acceptor_with_transport(
+        &self,
+        bind_addr: SocketAddr,
+        expected_sessions: Vec<(String, String)>,
+        transport: Option<TransportConfig>,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayCommand::StartAcceptor {
+                bind_addr,
+                expected_sessions,
+                transport,
+                compression: Vec::new(),
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)?
+    }
+
+    /// Reads back per-session counters (messages/bytes in and out,
+    /// heartbeats, test requests, resend requests, gap fills, rejects,
+    /// reconnects). Pass `None` for a gateway-wide summary, which also
+    /// populates `MeterSnapshot::active_sessions`.
+    pub async fn get_meter(&self, session_id: Option<u64>) -> Result<MeterSnapshot> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayCommand::GetMeter {
+                session_id,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)
+    }
+
+    /// Streams back every compliance-journal record stored for `session_id`
+    /// with `from_ts <= ts_millis <= to_ts`, for audit/debugging.
+    pub async fn replay_journal(
+        &self,
+        session_id: u64,
+        from_ts: u64,
+        to_ts: u64,
+    ) -> Result<Vec<JournalRecord>> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayCommand::ReplayJournal {
+                session_id,
+                from_ts,
+                to_ts,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)?
+    }
+
+    /// Gracefully shuts the gateway down: every acceptor stops taking new
+    /// connections and every live session (acceptor and initiator) sends a
+    /// Logout(35=5) before closing. Returns once
+    /// `GatewayConfig::shutdown_grace_period` has elapsed, which bounds how
+    /// long this waits regardless of how many sessions actually finished
+    /// draining in time.
+    pub async fn shutdown(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayCommand::Shutdown { respond_to: tx })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)
+    }
+
+    /// Lists every session the gateway currently knows a `SessionKey` for.
+    pub async fn list_sessions(&self) -> Result<Vec<(u64, SessionKey)>> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayCommand::ListSessions { respond_to: tx })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)
+    }
+
+    /// Requests a best-effort Logout(35=5) for a single live session.
+    /// Returns `FixgError::Session` if `session_id` was never registered,
+    /// and `FixgError::ChannelClosed` if its connection task has already
+    /// ended.
+    pub async fn logout_session(&self, session_id: u64) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(GatewayCommand::LogoutSession { session_id, respond_to: tx })
+            .await
+            .map_err(|_| FixgError::ChannelClosed)?;
+        rx.await.map_err(|_| FixgError::ChannelClosed)?
+    }
 }
 
 /// The main FIX gateway that manages sessions and client connections.
@@ -57,26 +1535,78 @@ pub struct Gateway;
 
 impl Gateway {
     pub async fn spawn(config: GatewayConfig) -> Result<GatewayHandle> {
+        // Enforces one live gateway per `log_directory`: a second process
+        // pointed at the same journal would race it for sequence-number
+        // state. Claim this *before* any other setup so a contended
+        // data directory fails fast.
+        let control_listener = match control::claim_control_socket(&config.log_directory).await? {
+            control::ControlClaim::Bound(listener) => listener,
+            control::ControlClaim::AlreadyRunning => {
+                return Err(FixgError::InvalidConfig(format!(
+                    "a gateway is already running against log_directory {:?}; use \
+                     control::send_control_request to talk to it instead of starting \
+                     a second instance",
+                    config.log_directory
+                )));
+            }
+        };
         let (cmd_tx, mut cmd_rx) = mpsc::channel::<GatewayCommand>(1024);
         let next_session_id = Arc::new(AtomicU64::new(0));
         let global_session_senders: Arc<RwLock<HashMap<u64, mpsc::Sender<OutboundPayload>>>> =
             Arc::new(RwLock::new(HashMap::new()));
         let clients: Arc<RwLock<Vec<mpsc::Sender<GatewayEvent>>>> =
             Arc::new(RwLock::new(Vec::new()));
+        // Admission control: tracks currently active sessions by `SessionKey`
+        // so `max_sessions`/`max_sessions_per_comp_id` can be enforced and
+        // duplicate logons for an already-active key rejected.
+        let active_sessions: Arc<RwLock<HashMap<SessionKey, u64>>> =
+            Arc::new(RwLock::new(HashMap::new()));
+        // Per-session counters, queryable via `GatewayHandle::get_meter`.
+        // Entries are registered when a session's connection task starts and
+        // are never removed, so counters remain readable after disconnect.
+        let meters: Arc<RwLock<HashMap<u64, Arc<SessionMeters>>>> =
+            Arc::new(RwLock::new(HashMap::new()));
+        // Per-session compliance journal sink and runtime-adjustable trace
+        // verbosity, queryable/settable via `GatewayCommand::ReplayJournal`
+        // and `ClientCommand::SetTraceLevel` without restarting the session.
+        let journal = make_journal(&config.journal);
+        let trace_levels: Arc<RwLock<HashMap<u64, Arc<TraceLevelHandle>>>> =
+            Arc::new(RwLock::new(HashMap::new()));
+        // Resolves a session_id to the `SessionKey` its journal is filed
+        // under, for `GatewayCommand::ReplayJournal`.
+        let session_keys: Arc<RwLock<HashMap<u64, SessionKey>>> =
+            Arc::new(RwLock::new(HashMap::new()));
+        let max_sessions = config.max_sessions;
+        let max_sessions_per_comp_id = config.max_sessions_per_comp_id;
+        let outbound_high_water_mark = config.outbound_high_water_mark;
+        let shutdown_grace_period = config.shutdown_grace_period;
         let store = make_store(&config.storage);
+        let transport = make_transport(&config.transport)?;
+        // Broadcasts a one-way, never-reset false->true transition to every
+        // live connection task and the accept loop(s) for graceful shutdown
+        // (see `GatewayCommand::Shutdown`).
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
         tokio::spawn({
             let next_session_id = Arc::clone(&next_session_id);
             let global_session_senders = Arc::clone(&global_session_senders);
             let clients = Arc::clone(&clients);
+            let active_sessions = Arc::clone(&active_sessions);
+            let meters = Arc::clone(&meters);
+            let journal = Arc::clone(&journal);
+            let trace_levels = Arc::clone(&trace_levels);
+            let session_keys = Arc::clone(&session_keys);
+            let shutdown_rx = shutdown_rx.clone();
             let bind_addr = config.bind_address;
             let auth = Arc::clone(&config.auth_strategy);
             let store = store.clone();
+            let transport = transport.clone();
             async move {
                 let mut _clients: Vec<ClientConnectionInternal> = Vec::new();
 
-                // Start TCP listener for acceptor mode
-                let listener = match TcpListener::bind(bind_addr).await {
+                // Start the acceptor's listener (a real TCP socket, or an
+                // in-memory registry entry for `TransportConfig::Memory`).
+                let listener = match transport.bind(bind_addr).await {
                     Ok(l) => l,
                     Err(e) => {
                         tracing::error!(error = %e, "Failed to bind listener");
@@ -85,196 +1615,26 @@ impl Gateway {
                 };
 
                 // Accept loop in background
-                tokio::spawn({
-                    let next_id = Arc::clone(&next_session_id);
-                    let clients = Arc::clone(&clients);
-                    let global_session_senders = Arc::clone(&global_session_senders);
-                    let auth = Arc::clone(&auth);
-                    async move {
-                        loop {
-                            match listener.accept().await {
-                                Ok((stream, _addr)) => {
-                                    let session_id = next_id.fetch_add(1, Ordering::Relaxed) + 1;
-                                    let (read_half, write_half) = stream.into_split();
-
-                                    let (app_out_tx, app_out_rx) =
-                                        mpsc::channel::<OutboundPayload>(1024);
-                                    {
-                                        let mut map = global_session_senders.write().await;
-                                        map.insert(session_id, app_out_tx.clone());
-                                    }
-
-                                    tokio::spawn({
-                                        let clients = Arc::clone(&clients);
-                                        let auth = Arc::clone(&auth);
-                                        async move {
-                                            let mut write_half = write_half;
-                                            let mut read_half = read_half;
-                                            let mut app_out_rx = app_out_rx;
-                                            let mut out_seq_num: u32 = 1;
-                                            let mut _in_seq_num: u32 = 0;
-                                            let mut last_rx: Instant = Instant::now();
-                                            let mut test_req_outstanding: Option<String> = None;
-                                            let mut hb_interval = Duration::from_secs(30);
-                                            let mut sender_comp = String::new();
-                                            let mut target_comp = String::new();
-                                            let mut read_buf = BytesMut::with_capacity(16 * 1024);
-
-                                            let mut tick = time::interval(Duration::from_secs(1));
-                                            tick.set_missed_tick_behavior(
-                                                time::MissedTickBehavior::Delay,
-                                            );
-
-                                            loop {
-                                                tokio::select! {
-                                                    biased;
-                                                    maybe_out = app_out_rx.recv() => {
-                                                        if let Some(payload) = maybe_out {
-                                                            match payload {
-                                                                OutboundPayload::Raw(bytes) => {
-                                                                    let _ = write_half.write_all(&bytes).await;
-                                                                }
-                                                                OutboundPayload::Admin(msg) => {
-                                                                    let mut fix = msg.into_fix(&target_comp, &sender_comp);
-                                                                    fix.set_field(34, out_seq_num.to_string());
-                                                                    out_seq_num += 1;
-                                                                    let bytes = protocol::encode(fix);
-                                                                    let _ = write_half.write_all(&bytes).await;
-                                                                }
-                                                            }
-                                                        } else { break; }
-                                                    }
-                                                    res = read_half.read_buf(&mut read_buf) => {
-                                                        match res {
-                                                            Ok(0) => {
-                                                                let senders = clients.read().await;
-                                                                for tx in senders.iter() {
-                                                                    let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::PeerClosed }).await;
-                                                                }
-                                                                break;
-                                                            }
-                                                            Ok(_) => {
-                                                                while let Some(msg_bytes) = protocol::try_extract_one(&mut read_buf) {
-                                                                    last_rx = Instant::now();
-                                                                    match protocol::decode(&msg_bytes) {
-                                                                        Ok(msg) => {
-                                                                            if let Some(seq) = msg.fields.get(&34) {
-                                                                                if let Ok(seq_val) = seq.parse::<u32>() { _in_seq_num = seq_val; }
-                                                                            }
-                                                                            match msg.msg_type {
-                                                                                FixMsgType::Logon => {
-                                                                                    if let Some(hb) = msg.fields.get(&108) {
-                                                                                        if let Ok(secs) = hb.parse::<u64>() { hb_interval = Duration::from_secs(secs); }
-                                                                                    }
-                                                                                    if let Some(s) = msg.fields.get(&49) { sender_comp = s.clone(); }
-                                                                                    if let Some(t) = msg.fields.get(&56) { target_comp = t.clone(); }
-
-                                                                                    // Validate using pluggable auth
-                                                                                    if !auth.validate_logon(&sender_comp, &target_comp) {
-                                                                                        let mut lo = protocol::build_logout(Some("Logon rejected"), &target_comp, &sender_comp);
-                                                                                        lo.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
-                                                                                        let lo_bytes = protocol::encode(lo);
-                                                                                        let _ = write_half.write_all(&lo_bytes).await;
-                                                                                        let senders = clients.read().await;
-                                                                                        for tx in senders.iter() {
-                                                                                            let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ApplicationRequested }).await;
-                                                                                        }
-                                                                                        break;
-                                                                                    }
-
-                                                                                    // Echo logon
-                                                                                    let mut logon = protocol::build_logon(hb_interval.as_secs() as u32, &target_comp, &sender_comp);
-                                                                                    logon.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
-                                                                                    let bytes = protocol::encode(logon);
-                                                                                    let _ = write_half.write_all(&bytes).await;
-
-                                                                                    let senders = clients.read().await;
-                                                                                    for tx in senders.iter() {
-                                                                                        let _ = tx.send(GatewayEvent::SessionActive { session_id }).await;
-                                                                                    }
-                                                                                }
-                                                                                FixMsgType::TestRequest => {
-                                                                                    let id = msg.fields.get(&112).cloned();
-                                                                                    let mut hb = protocol::build_heartbeat(id.as_deref(), &target_comp, &sender_comp);
-                                                                                    hb.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
-                                                                                    let hb_bytes = protocol::encode(hb);
-                                                                                    let _ = write_half.write_all(&hb_bytes).await;
-                                                                                }
-                                                                                FixMsgType::Logout => {
-                                                                                    let mut lo = protocol::build_logout(None, &target_comp, &sender_comp);
-                                                                                    lo.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
-                                                                                    let lo_bytes = protocol::encode(lo);
-                                                                                    let _ = write_half.write_all(&lo_bytes).await;
-                                                                                    let senders = clients.read().await;
-                                                                                    for tx in senders.iter() {
-                                                                                        let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ApplicationRequested }).await;
-                                                                                    }
-                                                                                    break;
-                                                                                }
-                                                                                FixMsgType::Heartbeat | FixMsgType::Unknown(_) => {}
-                                                                                FixMsgType::ResendRequest | FixMsgType::SequenceReset => {}
-                                                                            }
-                                                                            let msg_type = match msg.msg_type { FixMsgType::Unknown(_) => "?".to_string(), _ => protocol::msg_type_as_str(&msg.msg_type).to_string() };
-                                                                            let senders = clients.read().await;
-                                                                            for tx in senders.iter() {
-                                                                                let _ = tx.send(GatewayEvent::InboundMessage { session_id, msg_type: msg_type.clone(), payload: msg_bytes.clone() }).await;
-                                                                            }
-                                                                        }
-                                                                        Err(_) => {
-                                                                            let senders = clients.read().await;
-                                                                            for tx in senders.iter() {
-                                                                                let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError }).await;
-                                                                            }
-                                                                            break;
-                                                                        }
-                                                                    }
-                                                                }
-                                                            }
-                                                            Err(_) => {
-                                                                let senders = clients.read().await;
-                                                                for tx in senders.iter() {
-                                                                    let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::Unknown }).await;
-                                                                }
-                                                                break;
-                                                            }
-                                                        }
-                                                    }
-                                                    _ = tick.tick() => {
-                                                        let idle = last_rx.elapsed();
-                                                        if idle >= hb_interval * 3 {
-                                                            let senders = clients.read().await;
-                                                            for tx in senders.iter() {
-                                                                let _ = tx.send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::Timeout }).await;
-                                                            }
-                                                            break;
-                                                        } else if idle >= hb_interval * 2 {
-                                                            if test_req_outstanding.is_none() {
-                                                                let tr_id = format!("TR-{}", out_seq_num);
-                                                                let mut tr = protocol::build_test_request(&tr_id, &target_comp, &sender_comp);
-                                                                tr.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
-                                                                let tr_bytes = protocol::encode(tr);
-                                                                let _ = write_half.write_all(&tr_bytes).await;
-                                                                test_req_outstanding = Some(tr_id);
-                                                            }
-                                                        } else if idle >= hb_interval {
-                                                            let mut hb = protocol::build_heartbeat(None, &target_comp, &sender_comp);
-                                                            hb.set_field(34, out_seq_num.to_string()); out_seq_num += 1;
-                                                            let hb_bytes = protocol::encode(hb);
-                                                            let _ = write_half.write_all(&hb_bytes).await;
-                                                        }
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    });
-                                }
-                                Err(e) => {
-                                    tracing::error!(error = %e, "Accept failed");
-                                }
-                            }
-                        }
-                    }
-                });
+                tokio::spawn(run_accept_loop(
+                    listener,
+                    transport.clone(),
+                    Arc::clone(&next_session_id),
+                    Arc::clone(&clients),
+                    Arc::clone(&global_session_senders),
+                    None,
+                    Arc::clone(&active_sessions),
+                    Arc::clone(&auth),
+                    store.clone(),
+                    max_sessions,
+                    max_sessions_per_comp_id,
+                    None,
+                    Arc::clone(&meters),
+                    Arc::clone(&journal),
+                    Arc::clone(&trace_levels),
+                    Arc::clone(&session_keys),
+                    shutdown_rx.clone(),
+                    Vec::new(),
+                ));
 
                 while let Some(cmd) = cmd_rx.recv().await {
                     match cmd {
@@ -290,16 +1650,26 @@ impl Gateway {
                             let to_client_tx_clone = to_client_tx.clone();
                             let next_id = Arc::clone(&next_session_id);
                             let store = store.clone();
+                            let transport = transport.clone();
                             {
                                 let mut v = clients.write().await;
                                 v.push(to_client_tx.clone());
                             }
                             let global_session_senders = Arc::clone(&global_session_senders);
+                            let active_sessions = Arc::clone(&active_sessions);
+                            let auth = Arc::clone(&auth);
+                            let meters = Arc::clone(&meters);
+                            let journal = Arc::clone(&journal);
+                            let trace_levels = Arc::clone(&trace_levels);
+                            let session_keys = Arc::clone(&session_keys);
+                            let shutdown_rx = shutdown_rx.clone();
                             tokio::spawn(async move {
-                                let mut session_senders: HashMap<
-                                    u64,
-                                    mpsc::Sender<OutboundPayload>,
-                                > = HashMap::new();
+                                // Shared with any acceptor this client starts via
+                                // `ClientCommand::Listen`, so a single `ClientConnection`
+                                // owns both its initiated and accepted sessions.
+                                let session_senders: Arc<
+                                    RwLock<HashMap<u64, mpsc::Sender<OutboundPayload>>>,
+                                > = Arc::new(RwLock::new(HashMap::new()));
 
                                 while let Some(cc) = from_client_rx.recv().await {
                                     match cc {
@@ -309,253 +1679,238 @@ impl Gateway {
                                             sender_comp_id,
                                             target_comp_id,
                                             heartbeat_interval_secs,
+                                            reconnect,
+                                            reset_seq_num,
+                                            transport: transport_override,
+                                            begin_string,
+                                            default_appl_ver_id,
+                                            compression,
                                             respond_to,
                                         } => {
                                             let addr = format!("{}:{}", host, port);
-                                            match TcpStream::connect(addr).await {
-                                                Ok(stream) => {
+                                            let prospective_key = SessionKey {
+                                                sender_comp_id: sender_comp_id.clone(),
+                                                target_comp_id: target_comp_id.clone(),
+                                            };
+                                            let admitted = admit_session(
+                                                &*active_sessions.read().await,
+                                                &prospective_key,
+                                                max_sessions,
+                                                max_sessions_per_comp_id,
+                                            );
+                                            if !admitted {
+                                                let _ =
+                                                    respond_to.send(SessionHandle { session_id: 0, transport: TransportKind::default() });
+                                                let _ = to_client_tx_clone
+                                                    .send(GatewayEvent::Disconnected {
+                                                        session_id: 0,
+                                                        reason: DisconnectReason::ConnectionLimit,
+                                                    })
+                                                    .await;
+                                                continue;
+                                            }
+                                            // A per-call `transport` override builds a fresh
+                                            // `Transport` just for this session; otherwise the
+                                            // gateway-wide one (built once in `Gateway::spawn`) is
+                                            // shared, the prior behavior.
+                                            let transport: Arc<dyn crate::transport::Transport> =
+                                                match &transport_override {
+                                                    Some(cfg) => match make_transport(cfg) {
+                                                        Ok(t) => t,
+                                                        Err(e) => {
+                                                            let _ = respond_to.send(SessionHandle {
+                                                                session_id: 0,
+                                                                transport: TransportKind::default(),
+                                                            });
+                                                            tracing::error!(error = %e, "Invalid transport override");
+                                                            continue;
+                                                        }
+                                                    },
+                                                    None => transport.clone(),
+                                                };
+                                            let transport_kind = transport.kind();
+                                            let connected = match transport.connect(&addr, &host).await {
+                                                Ok((read_half, write_half)) => {
+                                                    crate::transport::negotiate_compression(
+                                                        read_half, write_half, &compression,
+                                                    )
+                                                    .await
+                                                    .map(|(r, w, _codec)| (r, w))
+                                                }
+                                                Err(e) => Err(e),
+                                            };
+                                            match connected {
+                                                Ok((read_half, write_half)) => {
                                                     let session_id =
                                                         next_id.fetch_add(1, Ordering::Relaxed) + 1;
-                                                    let (mut read_half, write_half) =
-                                                        stream.into_split();
 
                                                     // Create channel for application-driven outbound payloads to this session task
-                                                    let (app_out_tx, mut app_out_rx) =
+                                                    let (app_out_tx, app_out_rx) =
                                                         mpsc::channel::<OutboundPayload>(1024);
                                                     session_senders
+                                                        .write()
+                                                        .await
                                                         .insert(session_id, app_out_tx.clone());
                                                     {
                                                         let mut map =
                                                             global_session_senders.write().await;
                                                         map.insert(session_id, app_out_tx.clone());
                                                     }
+                                                    active_sessions
+                                                        .write()
+                                                        .await
+                                                        .insert(prospective_key.clone(), session_id);
+                                                    let session_meters =
+                                                        Arc::new(SessionMeters::default());
+                                                    meters
+                                                        .write()
+                                                        .await
+                                                        .insert(session_id, Arc::clone(&session_meters));
+                                                    let session_trace =
+                                                        Arc::new(TraceLevelHandle::default());
+                                                    trace_levels
+                                                        .write()
+                                                        .await
+                                                        .insert(session_id, Arc::clone(&session_trace));
+                                                    session_keys
+                                                        .write()
+                                                        .await
+                                                        .insert(session_id, prospective_key.clone());
 
-                                                    // Spawn session task owning write half, performing handshake, timers, and parsing
+                                                    // Spawn session task owning both transport halves,
+                                                    // performing handshake, timers, and parsing; on an
+                                                    // eligible disconnect it reconnects with backoff and
+                                                    // resumes sequence numbers from `store`.
                                                     let to_client_tx_reader =
                                                         to_client_tx_clone.clone();
                                                     let store = store.clone();
+                                                    let transport = transport.clone();
+                                                    let active_sessions =
+                                                        Arc::clone(&active_sessions);
+                                                    let journal = Arc::clone(&journal);
+                                                    let session_trace = Arc::clone(&session_trace);
+                                                    let session_keys = Arc::clone(&session_keys);
+                                                    let identity_for_event = target_comp_id.clone();
+                                                    let mut shutdown_rx = shutdown_rx.clone();
                                                     tokio::spawn(async move {
+                                                        let mut app_out_rx = app_out_rx;
+                                                        let mut read_half = read_half;
                                                         let mut write_half = write_half;
-                                                        let hb_interval = Duration::from_secs(
-                                                            heartbeat_interval_secs as u64,
-                                                        );
-                                                        let mut out_seq_num: u32 = 1;
-                                                        let mut in_seq_num: u32 = 0;
-                                                        let mut last_rx: Instant = Instant::now();
-                                                        let mut test_req_outstanding: Option<
-                                                            String,
-                                                        > = None;
                                                         let sess_key = SessionKey {
                                                             sender_comp_id: sender_comp_id.clone(),
                                                             target_comp_id: target_comp_id.clone(),
                                                         };
 
-                                                        // Send Logon
-                                                        let mut logon = protocol::build_logon(
-                                                            heartbeat_interval_secs,
-                                                            &sender_comp_id,
-                                                            &target_comp_id,
-                                                        );
-                                                        logon
-                                                            .set_field(34, out_seq_num.to_string());
-                                                        let seq_for_store = out_seq_num;
-                                                        out_seq_num += 1;
-                                                        let logon_bytes = protocol::encode(logon);
-                                                        let _ = write_half
-                                                            .write_all(&logon_bytes)
-                                                            .await;
-                                                        let _ = store
-                                                            .append_bytes(
+                                                        let mut reset_seq_num = reset_seq_num;
+                                                        loop {
+                                                            let send_reset = reset_seq_num;
+                                                            let (start_out_seq, start_in_seq) =
+                                                                if send_reset {
+                                                                    let _ = store
+                                                                        .reset_sequences(&sess_key)
+                                                                        .await;
+                                                                    reset_seq_num = false;
+                                                                    (1, 1)
+                                                                } else {
+                                                                    let out = store
+                                                                        .last_outbound_seq(&sess_key)
+                                                                        .await
+                                                                        .ok()
+                                                                        .flatten()
+                                                                        .unwrap_or(0)
+                                                                        + 1;
+                                                                    let inb = store
+                                                                        .last_inbound_seq(&sess_key)
+                                                                        .await
+                                                                        .ok()
+                                                                        .flatten()
+                                                                        .unwrap_or(0)
+                                                                        + 1;
+                                                                    (out, inb)
+                                                                };
+                                                            let reason = run_initiator_connection(
+                                                                write_half,
+                                                                read_half,
+                                                                &mut app_out_rx,
+                                                                session_id,
+                                                                &sender_comp_id,
+                                                                &target_comp_id,
+                                                                heartbeat_interval_secs,
+                                                                &store,
                                                                 &sess_key,
-                                                                Direction::Outbound,
-                                                                Some(seq_for_store),
-                                                                now_millis(),
-                                                                logon_bytes.as_ref(),
+                                                                &to_client_tx_reader,
+                                                                start_out_seq,
+                                                                start_in_seq,
+                                                                send_reset,
+                                                                &session_meters,
+                                                                transport_kind,
+                                                                &journal,
+                                                                &session_trace,
+                                                                &mut shutdown_rx,
+                                                                &begin_string,
+                                                                default_appl_ver_id.as_deref(),
                                                             )
                                                             .await;
 
-                                                        // Timers
-                                                        let mut interval =
-                                                            time::interval(hb_interval);
-                                                        interval.set_missed_tick_behavior(
-                                                            time::MissedTickBehavior::Delay,
-                                                        );
-
-                                                        let mut read_buf =
-                                                            BytesMut::with_capacity(16 * 1024);
-
-                                                        loop {
-                                                            tokio::select! {
-                                                                biased;
-                                                                // Application outbound payloads
-                                                                maybe_out = app_out_rx.recv() => {
-                                                                    if let Some(payload) = maybe_out {
-                                                                        match payload {
-                                                                            OutboundPayload::Raw(bytes) => {
-                                                                                let _ = write_half.write_all(&bytes).await;
-                                                                                let _ = store.append_bytes(&sess_key, Direction::Outbound, None, now_millis(), bytes.as_ref()).await;
-                                                                            }
-                                                                            OutboundPayload::Admin(msg) => {
-                                                                                let mut fix = msg.into_fix(&sender_comp_id, &target_comp_id);
-                                                                                fix.set_field(34, out_seq_num.to_string());
-                                                                                let seq_for_store = out_seq_num;
-                                                                                out_seq_num += 1;
-                                                                                let bytes = protocol::encode(fix);
-                                                                                let _ = write_half.write_all(&bytes).await;
-                                                                                let _ = store.append_bytes(&sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), bytes.as_ref()).await;
-                                                                            }
-                                                                        }
-                                                                    } else {
-                                                                        break;
-                                                                    }
-                                                                }
-                                                                // Network reads
-                                                                res = read_half.read_buf(&mut read_buf) => {
-                                                                    match res {
-                                                                        Ok(0) => {
-                                                                            let _ = to_client_tx_reader
-                                                                                .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::PeerClosed })
-                                                                                .await;
-                                                                            break;
-                                                                        }
-                                                                        Ok(_n) => {
-                                                                            // Try extract full messages
-                                                                            while let Some(msg_bytes) = protocol::try_extract_one(&mut read_buf) {
-                                                                                last_rx = Instant::now();
-                                                                                match protocol::decode(&msg_bytes) {
-                                                                                    Ok(msg) => {
-                                                                                        // Check seqnum if present
-                                                                                        if let Some(seq) = msg.fields.get(&34) {
-                                                                                            if let Ok(seq_val) = seq.parse::<u32>() { in_seq_num = seq_val; }
-                                                                                        }
-                                                                                        // Journal inbound
-                                                                                        let inbound_seq = msg.fields.get(&34).and_then(|s| s.parse::<u32>().ok());
-                                                                                        let _ = store.append_bytes(&sess_key, Direction::Inbound, inbound_seq, now_millis(), msg_bytes.as_ref()).await;
-
-                                                                                        match msg.msg_type {
-                                                                                            FixMsgType::Logon => {
-                                                                                                let _ = to_client_tx_reader
-                                                                                                    .send(GatewayEvent::SessionActive { session_id })
-                                                                                                    .await;
-                                                                                            }
-                                                                                            FixMsgType::Heartbeat => {
-                                                                                                if let Some(id) = msg.fields.get(&112) {
-                                                                                                    if test_req_outstanding.as_deref() == Some(id) {
-                                                                                                        test_req_outstanding = None;
-                                                                                                    }
-                                                                                                }
-                                                                                            }
-                                                                                            FixMsgType::TestRequest => {
-                                                                                                let tr_id = msg.fields.get(&112).cloned();
-                                                                                                let mut hb = protocol::build_heartbeat(tr_id.as_deref(), &sender_comp_id, &target_comp_id);
-                                                                                                hb.set_field(34, out_seq_num.to_string());
-                                                                                                let seq_for_store = out_seq_num;
-                                                                                                out_seq_num += 1;
-                                                                                                let hb_bytes = protocol::encode(hb);
-                                                                                                let _ = write_half.write_all(&hb_bytes).await;
-                                                                                                let _ = store.append_bytes(&sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), hb_bytes.as_ref()).await;
-                                                                                            }
-                                                                                            FixMsgType::ResendRequest => {
-                                                                                                let begin = msg.fields.get(&7).and_then(|s| s.parse::<u32>().ok()).unwrap_or(1);
-                                                                                                let end = msg.fields.get(&16).and_then(|s| s.parse::<u32>().ok()).unwrap_or(in_seq_num);
-                                                                                                if let Ok(chunks) = store.load_outbound_range(&sess_key, begin, end).await {
-                                                                                                    for b in chunks {
-                                                                                                        if let Ok(mut m) = protocol::decode(&b) {
-                                                                                                            // Mark as possible duplicate and set OrigSendingTime
-                                                                                                            m.set_field(43, "Y");
-                                                                                                            m.set_field(122, format!("{}", now_millis()));
-                                                                                                            let new_b = protocol::encode(m);
-                                                                                                            let _ = write_half.write_all(&new_b).await;
-                                                                                                        } else {
-                                                                                                            let _ = write_half.write_all(&b).await;
-                                                                                                        }
-                                                                                                    }
-                                                                                                }
-                                                                                            }
-                                                                                            FixMsgType::Logout => {
-                                                                                                // Echo logout and close
-                                                                                                let mut lo = protocol::build_logout(None, &sender_comp_id, &target_comp_id);
-                                                                                                lo.set_field(34, out_seq_num.to_string());
-                                                                                                let seq_for_store = out_seq_num;
-                                                                                                out_seq_num += 1;
-                                                                                                let lo_bytes = protocol::encode(lo);
-                                                                                                let _ = write_half.write_all(&lo_bytes).await;
-                                                                                                let _ = store.append_bytes(&sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), lo_bytes.as_ref()).await;
-                                                                                                let _ = to_client_tx_reader
-                                                                                                    .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ApplicationRequested })
-                                                                                                    .await;
-                                                                                                break;
-                                                                                            }
-                                                                                            FixMsgType::SequenceReset | FixMsgType::Unknown(_) => {}
-                                                                                        }
-                                                                                        // Forward inbound to client as event
-                                                                                        let msg_type = match msg.msg_type { FixMsgType::Unknown(_) => "?".to_string(), _ => protocol::msg_type_as_str(&msg.msg_type).to_string() };
-                                                                                        let _ = to_client_tx_reader
-                                                                                            .send(GatewayEvent::InboundMessage { session_id, msg_type, payload: msg_bytes.clone() })
-                                                                                            .await;
-                                                                                    }
-                                                                                    Err(_) => {
-                                                                                        let _ = to_client_tx_reader
-                                                                                            .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::ProtocolError })
-                                                                                            .await;
-                                                                                        break;
-                                                                                    }
-                                                                                }
-                                                                            }
-                                                                        }
-                                                                        Err(_) => {
-                                                                            let _ = to_client_tx_reader
-                                                                                .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::Unknown })
-                                                                                .await;
-                                                                            break;
-                                                                        }
-                                                                    }
-                                                                }
-                                                                // Heartbeat timers
-                                                                _ = interval.tick() => {
-                                                                    let idle = last_rx.elapsed();
-                                                                    if idle >= hb_interval * 3 {
-                                                                        let _ = to_client_tx_reader
-                                                                            .send(GatewayEvent::Disconnected { session_id, reason: DisconnectReason::Timeout })
-                                                                            .await;
-                                                                        break;
-                                                                    } else if idle >= hb_interval * 2 {
-                                                                        if test_req_outstanding.is_none() {
-                                                                            let tr_id = format!("TR-{}", out_seq_num);
-                                                                            let mut tr = protocol::build_test_request(&tr_id, &sender_comp_id, &target_comp_id);
-                                                                            tr.set_field(34, out_seq_num.to_string());
-                                                                            let seq_for_store = out_seq_num;
-                                                                            out_seq_num += 1;
-                                                                            let tr_bytes = protocol::encode(tr);
-                                                                            let _ = write_half.write_all(&tr_bytes).await;
-                                                                            let _ = store.append_bytes(&sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), tr_bytes.as_ref()).await;
-                                                                            test_req_outstanding = Some(tr_id);
-                                                                        }
-                                                                    } else if idle >= hb_interval {
-                                                                        let mut hb = protocol::build_heartbeat(None, &sender_comp_id, &target_comp_id);
-                                                                        hb.set_field(34, out_seq_num.to_string());
-                                                                        let seq_for_store = out_seq_num;
-                                                                        out_seq_num += 1;
-                                                                        let hb_bytes = protocol::encode(hb);
-                                                                        let _ = write_half.write_all(&hb_bytes).await;
-                                                                        let _ = store.append_bytes(&sess_key, Direction::Outbound, Some(seq_for_store), now_millis(), hb_bytes.as_ref()).await;
-                                                                    }
+                                                            // Reconnect on anything except a deliberate
+                                                            // stop: the application asked to disconnect, or
+                                                            // the gateway itself is shutting down (retrying
+                                                            // mid-shutdown would fight
+                                                            // `GatewayHandle::shutdown`'s grace period).
+                                                            // `ProtocolError` is retried too -- a malformed
+                                                            // frame from a flaky link shouldn't need a manual
+                                                            // reconnect any more than a dropped TCP connection
+                                                            // would.
+                                                            let retryable = !matches!(
+                                                                reason,
+                                                                DisconnectReason::ApplicationRequested
+                                                                    | DisconnectReason::Shutdown
+                                                            );
+                                                            let Some(ref rc) = reconnect else {
+                                                                break;
+                                                            };
+                                                            if !retryable {
+                                                                break;
+                                                            }
+                                                            match reconnect_with_backoff(
+                                                                &transport,
+                                                                &addr,
+                                                                &host,
+                                                                session_id,
+                                                                rc,
+                                                                &to_client_tx_reader,
+                                                                &session_meters,
+                                                                &compression,
+                                                            )
+                                                            .await
+                                                            {
+                                                                Some((r, w)) => {
+                                                                    read_half = r;
+                                                                    write_half = w;
                                                                 }
+                                                                None => break,
                                                             }
                                                         }
+                                                        active_sessions.write().await.remove(&sess_key);
+                                                        session_keys.write().await.remove(&session_id);
+                                                        let _ = to_client_tx_reader
+                                                            .send(GatewayEvent::SessionTerminated { session_id })
+                                                            .await;
                                                     });
 
                                                     let _ = to_client_tx_clone
                                                         .send(GatewayEvent::SessionActive {
                                                             session_id,
+                                                            identity: identity_for_event,
+                                                            transport: transport_kind,
                                                         })
                                                         .await;
                                                     let _ = respond_to
-                                                        .send(SessionHandle { session_id });
+                                                        .send(SessionHandle { session_id, transport: transport_kind });
                                                 }
                                                 Err(e) => {
                                                     let _ = respond_to
-                                                        .send(SessionHandle { session_id: 0 });
+                                                        .send(SessionHandle { session_id: 0, transport: TransportKind::default() });
                                                     let _ = to_client_tx_clone
                                                         .send(GatewayEvent::Disconnected {
                                                             session_id: 0,
@@ -566,20 +1921,121 @@ impl Gateway {
                                                 }
                                             }
                                         }
+                                        ClientCommand::Listen {
+                                            bind_addr,
+                                            expected_sessions,
+                                            transport: transport_override,
+                                            compression,
+                                            respond_to,
+                                        } => {
+                                            let acceptor_transport: Arc<dyn crate::transport::Transport> =
+                                                match &transport_override {
+                                                    Some(cfg) => match make_transport(cfg) {
+                                                        Ok(t) => t,
+                                                        Err(e) => {
+                                                            let _ = respond_to.send(Err(e));
+                                                            continue;
+                                                        }
+                                                    },
+                                                    None => transport.clone(),
+                                                };
+                                            let listener = match acceptor_transport.bind(bind_addr).await {
+                                                Ok(l) => l,
+                                                Err(e) => {
+                                                    let _ = respond_to.send(Err(e));
+                                                    continue;
+                                                }
+                                            };
+                                            let _ = respond_to.send(Ok(()));
+                                            // Events for sessions accepted here go only to this
+                                            // client, mirroring the single-sender shape `clients`
+                                            // uses for the gateway-wide acceptor.
+                                            let this_client: Arc<RwLock<Vec<mpsc::Sender<GatewayEvent>>>> =
+                                                Arc::new(RwLock::new(vec![to_client_tx_clone.clone()]));
+                                            tokio::spawn(run_accept_loop(
+                                                listener,
+                                                acceptor_transport,
+                                                Arc::clone(&next_id),
+                                                this_client,
+                                                Arc::clone(&global_session_senders),
+                                                Some(Arc::clone(&session_senders)),
+                                                Arc::clone(&active_sessions),
+                                                Arc::clone(&auth),
+                                                store.clone(),
+                                                max_sessions,
+                                                max_sessions_per_comp_id,
+                                                Some(Arc::new(expected_sessions)),
+                                                Arc::clone(&meters),
+                                                Arc::clone(&journal),
+                                                Arc::clone(&trace_levels),
+                                                Arc::clone(&session_keys),
+                                                shutdown_rx.clone(),
+                                                compression,
+                                            ));
+                                        }
+                                        ClientCommand::SetTraceLevel { session_id, level } => {
+                                            if let Some(handle) = trace_levels.read().await.get(&session_id) {
+                                                handle.set(level);
+                                            }
+                                        }
                                         ClientCommand::Send {
                                             session_id,
                                             payload,
+                                            respond_to,
                                         } => {
-                                            if let Some(tx) = session_senders.get_mut(&session_id) {
-                                                let _ =
-                                                    tx.send(OutboundPayload::Raw(payload)).await;
-                                            }
+                                            let tx = session_senders.read().await.get(&session_id).cloned();
+                                            let tx = match tx {
+                                                Some(tx) => Some(tx),
+                                                None => global_session_senders.read().await.get(&session_id).cloned(),
+                                            };
+                                            try_enqueue_outbound(
+                                                tx,
+                                                OutboundPayload::Raw(payload),
+                                                session_id,
+                                                outbound_high_water_mark,
+                                                &to_client_tx_clone,
+                                                respond_to,
+                                            )
+                                            .await;
                                         }
                                         ClientCommand::SendAdmin {
-                                            session_id, msg, ..
+                                            session_id,
+                                            msg,
+                                            respond_to,
+                                            ..
                                         } => {
-                                            if let Some(tx) = session_senders.get_mut(&session_id) {
-                                                let _ = tx.send(OutboundPayload::Admin(msg)).await;
+                                            let tx = session_senders.read().await.get(&session_id).cloned();
+                                            let tx = match tx {
+                                                Some(tx) => Some(tx),
+                                                None => global_session_senders.read().await.get(&session_id).cloned(),
+                                            };
+                                            try_enqueue_outbound(
+                                                tx,
+                                                OutboundPayload::Admin(msg),
+                                                session_id,
+                                                outbound_high_water_mark,
+                                                &to_client_tx_clone,
+                                                respond_to,
+                                            )
+                                            .await;
+                                        }
+                                        ClientCommand::Flush { session_id, respond_to } => {
+                                            let tx = session_senders.read().await.get(&session_id).cloned();
+                                            let tx = match tx {
+                                                Some(tx) => Some(tx),
+                                                None => global_session_senders.read().await.get(&session_id).cloned(),
+                                            };
+                                            match tx {
+                                                Some(tx) => {
+                                                    if let Err(e) = tx.send(OutboundPayload::Flush(respond_to)).await {
+                                                        if let OutboundPayload::Flush(respond_to) = e.0 {
+                                                            let _ = respond_to.send(());
+                                                        }
+                                                    }
+                                                }
+                                                None => {
+                                                    let _ = respond_to.send(());
+                                                }
                                             }
                                         }
                                     }
@@ -597,7 +2053,133 @@ impl Gateway {
                                 _to_client_tx: to_client_tx,
                             });
                         }
-                        GatewayCommand::Shutdown => {
+                        GatewayCommand::StartAcceptor {
+                            bind_addr,
+                            expected_sessions,
+                            transport: transport_override,
+                            compression,
+                            respond_to,
+                        } => {
+                            let acceptor_transport: Arc<dyn crate::transport::Transport> =
+                                match &transport_override {
+                                    Some(cfg) => match make_transport(cfg) {
+                                        Ok(t) => t,
+                                        Err(e) => {
+                                            let _ = respond_to.send(Err(e));
+                                            continue;
+                                        }
+                                    },
+                                    None => transport.clone(),
+                                };
+                            let listener = match acceptor_transport.bind(bind_addr).await {
+                                Ok(l) => l,
+                                Err(e) => {
+                                    let _ = respond_to.send(Err(e));
+                                    continue;
+                                }
+                            };
+                            let _ = respond_to.send(Ok(()));
+                            tokio::spawn(run_accept_loop(
+                                listener,
+                                acceptor_transport,
+                                Arc::clone(&next_session_id),
+                                Arc::clone(&clients),
+                                Arc::clone(&global_session_senders),
+                                None,
+                                Arc::clone(&active_sessions),
+                                Arc::clone(&auth),
+                                store.clone(),
+                                max_sessions,
+                                max_sessions_per_comp_id,
+                                Some(Arc::new(expected_sessions)),
+                                Arc::clone(&meters),
+                                Arc::clone(&journal),
+                                Arc::clone(&trace_levels),
+                                Arc::clone(&session_keys),
+                                shutdown_rx.clone(),
+                                compression,
+                            ));
+                        }
+                        GatewayCommand::ReplayJournal {
+                            session_id,
+                            from_ts,
+                            to_ts,
+                            respond_to,
+                        } => {
+                            let key = session_keys.read().await.get(&session_id).cloned();
+                            let result = match key {
+                                Some(key) => journal
+                                    .query(&key, from_ts, to_ts)
+                                    .await
+                                    .map_err(FixgError::Io),
+                                None => Err(FixgError::Session(format!(
+                                    "unknown session_id {session_id}"
+                                ))),
+                            };
+                            let _ = respond_to.send(result);
+                        }
+                        GatewayCommand::GetMeter {
+                            session_id,
+                            respond_to,
+                        } => {
+                            let snapshot = match session_id {
+                                Some(id) => meters
+                                    .read()
+                                    .await
+                                    .get(&id)
+                                    .map(|m| m.snapshot(Some(id)))
+                                    .unwrap_or(MeterSnapshot { session_id: Some(id), ..Default::default() }),
+                                None => {
+                                    let all = meters.read().await;
+                                    let summary = SessionMeters::default();
+                                    for m in all.values() {
+                                        summary.add_from(&m.snapshot(None));
+                                    }
+                                    let mut snapshot = summary.snapshot(None);
+                                    snapshot.active_sessions = active_sessions.read().await.len() as u64;
+                                    snapshot
+                                }
+                            };
+                            let _ = respond_to.send(snapshot);
+                        }
+                        GatewayCommand::ListSessions { respond_to } => {
+                            let sessions = session_keys
+                                .read()
+                                .await
+                                .iter()
+                                .map(|(id, key)| (*id, key.clone()))
+                                .collect();
+                            let _ = respond_to.send(sessions);
+                        }
+                        GatewayCommand::LogoutSession { session_id, respond_to } => {
+                            let tx = global_session_senders.read().await.get(&session_id).cloned();
+                            let result = match tx {
+                                Some(tx) => {
+                                    let msg = AdminMessage::Logout {
+                                        text: Some("requested via control socket".to_string()),
+                                        session_status: None,
+                                    };
+                                    tx.send(OutboundPayload::Admin(msg))
+                                        .await
+                                        .map_err(|_| FixgError::ChannelClosed)
+                                }
+                                None => Err(FixgError::Session(format!(
+                                    "unknown session_id {session_id}"
+                                ))),
+                            };
+                            let _ = respond_to.send(result);
+                        }
+                        GatewayCommand::Shutdown { respond_to } => {
+                            // Flips the watch once; every live connection task and
+                            // accept loop observes it via `changed()`, sends its own
+                            // Logout (where it has enough of a handshake to address
+                            // one), and exits on its own. We don't track individual
+                            // task completion, so this simply waits out the grace
+                            // period as an upper bound before tearing down the
+                            // command loop regardless of stragglers.
+                            let _ = shutdown_tx.send(true);
+                            time::sleep(shutdown_grace_period).await;
+                            let _ = respond_to.send(());
                             break;
                         }
                     }
@@ -605,6 +2187,11 @@ impl Gateway {
             }
         });
 
+        // Runtime admin interface over the same socket that just enforced
+        // the singleton guard: list sessions, request a session logout, or
+        // trigger shutdown without a registered `FixClient`.
+        tokio::spawn(control::serve(control_listener, GatewayHandle { cmd_tx: cmd_tx.clone() }));
+
         Ok(GatewayHandle { cmd_tx })
     }
 }
@@ -621,10 +2208,99 @@ struct ClientConnectionInternal {
     _to_client_tx: mpsc::Sender<GatewayEvent>,
 }
 
+/// Per-session counters for `GatewayCommand::GetMeter`, mirroring rumqtt's
+/// router/connection meters. Every field is an independently-incrementing
+/// atomic so the hot path never takes a lock to update one.
+#[derive(Debug, Default)]
+pub struct SessionMeters {
+    pub messages_in: AtomicU64,
+    pub messages_out: AtomicU64,
+    pub bytes_in: AtomicU64,
+    pub bytes_out: AtomicU64,
+    pub heartbeats_sent: AtomicU64,
+    pub test_requests_sent: AtomicU64,
+    pub resend_requests_sent: AtomicU64,
+    pub gap_fills_sent: AtomicU64,
+    pub rejects: AtomicU64,
+    pub reconnects: AtomicU64,
+    /// Round-trip latency between sending a TestRequest(35=1) and receiving
+    /// the peer's matching Heartbeat(35=0) echo, in nanoseconds. Gives
+    /// p50/p90/p99/p999 tail visibility that a plain sum-and-max can't.
+    pub test_request_latency: crate::metrics::LatencyHistogram,
+}
+
+impl SessionMeters {
+    fn snapshot(&self, session_id: Option<u64>) -> MeterSnapshot {
+        MeterSnapshot {
+            session_id,
+            messages_in: self.messages_in.load(Ordering::Relaxed),
+            messages_out: self.messages_out.load(Ordering::Relaxed),
+            bytes_in: self.bytes_in.load(Ordering::Relaxed),
+            bytes_out: self.bytes_out.load(Ordering::Relaxed),
+            heartbeats_sent: self.heartbeats_sent.load(Ordering::Relaxed),
+            test_requests_sent: self.test_requests_sent.load(Ordering::Relaxed),
+            resend_requests_sent: self.resend_requests_sent.load(Ordering::Relaxed),
+            gap_fills_sent: self.gap_fills_sent.load(Ordering::Relaxed),
+            rejects: self.rejects.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+            active_sessions: 0,
+            test_request_latency_p50_ns: self.test_request_latency.p50(),
+            test_request_latency_p99_ns: self.test_request_latency.p99(),
+            test_request_latency_max_ns: self.test_request_latency.max(),
+        }
+    }
+
+    fn add_from(&self, other: &MeterSnapshot) {
+        self.messages_in.fetch_add(other.messages_in, Ordering::Relaxed);
+        self.messages_out.fetch_add(other.messages_out, Ordering::Relaxed);
+        self.bytes_in.fetch_add(other.bytes_in, Ordering::Relaxed);
+        self.bytes_out.fetch_add(other.bytes_out, Ordering::Relaxed);
+        self.heartbeats_sent.fetch_add(other.heartbeats_sent, Ordering::Relaxed);
+        self.test_requests_sent.fetch_add(other.test_requests_sent, Ordering::Relaxed);
+        self.resend_requests_sent.fetch_add(other.resend_requests_sent, Ordering::Relaxed);
+        self.gap_fills_sent.fetch_add(other.gap_fills_sent, Ordering::Relaxed);
+        self.rejects.fetch_add(other.rejects, Ordering::Relaxed);
+        self.reconnects.fetch_add(other.reconnects, Ordering::Relaxed);
+    }
+}
+
+/// Cloneable point-in-time read of one session's [`SessionMeters`], or the
+/// gateway-wide summary when `session_id` is `None` (in which case
+/// `active_sessions` is also populated).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeterSnapshot {
+    pub session_id: Option<u64>,
+    pub messages_in: u64,
+    pub messages_out: u64,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    pub heartbeats_sent: u64,
+    pub test_requests_sent: u64,
+    pub resend_requests_sent: u64,
+    pub gap_fills_sent: u64,
+    pub rejects: u64,
+    pub reconnects: u64,
+    /// Number of currently active sessions. Only populated on the
+    /// gateway-wide summary.
+    pub active_sessions: u64,
+    /// TestRequest-to-Heartbeat round-trip latency percentiles, in
+    /// nanoseconds. Only meaningful on a per-session snapshot (`session_id
+    /// = Some(_)`): unlike the counters above, histogram buckets aren't
+    /// meaningfully summable across sessions with a plain `add_from`, so the
+    /// gateway-wide summary always reports these as `0`.
+    pub test_request_latency_p50_ns: u64,
+    pub test_request_latency_p99_ns: u64,
+    pub test_request_latency_max_ns: u64,
+}
+
 #[derive(Debug)]
 pub enum GatewayEvent {
     SessionActive {
         session_id: u64,
+        /// Identity the peer authenticated as (see `AuthOutcome::Accepted`).
+        identity: String,
+        /// Which concrete transport (TCP/TLS/WebSocket) this session negotiated.
+        transport: TransportKind,
     },
     InboundMessage {
         session_id: u64,
@@ -635,6 +2311,59 @@ pub enum GatewayEvent {
         session_id: u64,
         reason: DisconnectReason,
     },
+    /// Emitted before each automatic reconnect attempt (see [`crate::session::ReconnectConfig`]).
+    Reconnecting {
+        session_id: u64,
+        /// 1-based reconnect attempt number.
+        attempt: u32,
+        /// Backoff delay being waited out before this attempt, in milliseconds.
+        delay_ms: u64,
+    },
+    /// Emitted when an inbound MsgSeqNum(34) gap is detected and a
+    /// ResendRequest(35=2) covering `[from, to]` has been sent to the peer.
+    ResendInProgress {
+        session_id: u64,
+        /// BeginSeqNo(7) of the requested range.
+        from: u32,
+        /// EndSeqNo(16) of the requested range.
+        to: u32,
+    },
+    /// Emitted whenever a `ClientCommand::Send`/`SendAdmin` finds the
+    /// session's outbound queue at or above `GatewayConfig::outbound_high_water_mark`,
+    /// i.e. the connection's writer task is falling behind. Mirrors the
+    /// corresponding `SendAck::Queued` returned to that send's caller.
+    Backpressure {
+        session_id: u64,
+    },
+    /// Emitted once a gap-recovery round trip finishes: either the peer's
+    /// replayed messages closed a gap we detected (see `ResendInProgress`),
+    /// or we finished replaying a range the peer requested via its own
+    /// ResendRequest(35=2). `recovered` is the number of application
+    /// messages actually replayed/received, excluding any admin-only
+    /// sub-range collapsed into a SequenceReset/GapFill.
+    RecoveryComplete {
+        session_id: u64,
+        recovered: u32,
+    },
+    /// Emitted when the peer has been silent for longer than the heartbeat
+    /// interval, right before the gateway sends an automatic TestRequest(35=1)
+    /// probing whether it's still there. If no matching Heartbeat(35=0)
+    /// arrives in time, the session disconnects with
+    /// `DisconnectReason::HeartbeatTimeout`.
+    HeartbeatTimeout {
+        session_id: u64,
+    },
+    /// Emitted once the gateway will never revive `session_id`: an acceptor
+    /// connection closed (accepted sessions aren't reconnected, a fresh one
+    /// is accepted under a new `session_id` instead), or an initiator's
+    /// automatic reconnect gave up (no `ReconnectConfig`, a non-retryable
+    /// `DisconnectReason`, or backoff exhausted). Always follows a
+    /// `Disconnected` for the same `session_id`; unlike `Disconnected`, which
+    /// also fires for reconnects the gateway is about to retry transparently,
+    /// this is the client's signal to drop its tracking of the session.
+    SessionTerminated {
+        session_id: u64,
+    },
 }
 
 #[derive(Debug)]
@@ -643,7 +2372,60 @@ pub enum GatewayCommand {
         library_id: i32,
         respond_to: oneshot::Sender<ClientConnection>,
     },
-    Shutdown,
+    /// Starts a gateway-wide acceptor on `bind_addr`: accepted sessions are
+    /// gated against the `expected_sessions` (sender_comp_id, target_comp_id)
+    /// allowlist in addition to the configured `AuthStrategy`, and their
+    /// events are broadcast to every registered client, the same as the
+    /// acceptor bound at startup from `GatewayConfig::bind_address`.
+    StartAcceptor {
+        bind_addr: SocketAddr,
+        expected_sessions: Vec<(String, String)>,
+        /// Overrides the gateway's configured transport for this acceptor
+        /// only. `None` uses the gateway-wide transport.
+        transport: Option<TransportConfig>,
+        /// Compression codecs this acceptor offers to each connecting
+        /// initiator during the post-connect capability handshake.
+        compression: Vec<crate::transport::CompressionKind>,
+        respond_to: oneshot::Sender<Result<()>>,
+    },
+    /// Reads back a session's counters, or the gateway-wide summary across
+    /// all sessions if `session_id` is `None`. Responds with a zeroed
+    /// snapshot if `session_id` names a session that was never registered.
+    GetMeter {
+        session_id: Option<u64>,
+        respond_to: oneshot::Sender<MeterSnapshot>,
+    },
+    /// Streams back every compliance-journal record stored for `session_id`
+    /// with `from_ts <= ts_millis <= to_ts`, for audit/debugging. Responds
+    /// with `FixgError::Session` if `session_id` names a session whose
+    /// `SessionKey` isn't (or is no longer) known to the gateway.
+    ReplayJournal {
+        session_id: u64,
+        from_ts: u64,
+        to_ts: u64,
+        respond_to: oneshot::Sender<Result<Vec<JournalRecord>>>,
+    },
+    /// Triggers a graceful shutdown: stops accepting new connections, sends
+    /// a best-effort Logout(35=5) from every live session, then waits up to
+    /// `GatewayConfig::shutdown_grace_period` before tearing down the
+    /// gateway's command loop and responding.
+    Shutdown {
+        respond_to: oneshot::Sender<()>,
+    },
+    /// Lists every session the gateway currently knows a `SessionKey` for
+    /// (acceptor or initiator), keyed by `session_id`. Backs the control
+    /// socket's [`crate::control::ControlRequest::ListSessions`].
+    ListSessions {
+        respond_to: oneshot::Sender<Vec<(u64, SessionKey)>>,
+    },
+    /// Queues a best-effort Logout(35=5) for a single live session, same as
+    /// `ClientCommand::SendAdmin` would, but reachable without a registered
+    /// client -- used by the control socket's
+    /// [`crate::control::ControlRequest::LogoutSession`].
+    LogoutSession {
+        session_id: u64,
+        respond_to: oneshot::Sender<Result<()>>,
+    },
 }
 
 #[derive(Debug)]
@@ -654,26 +2436,172 @@ pub enum ClientCommand {
         sender_comp_id: String,
         target_comp_id: String,
         heartbeat_interval_secs: u32,
+        reconnect: Option<crate::session::ReconnectConfig>,
+        reset_seq_num: bool,
+        /// Overrides the gateway's configured transport for this session
+        /// only. `None` uses the gateway-wide transport.
+        transport: Option<TransportConfig>,
+        /// BeginString(8) this session emits, e.g. `"FIXT.1.1"` to speak the
+        /// transport/application version split. See `SessionConfig::begin_string`.
+        begin_string: String,
+        /// Expected DefaultApplVerID(1137); `None` skips the check. See
+        /// `SessionConfig::default_appl_ver_id`.
+        default_appl_ver_id: Option<String>,
+        /// Compression codecs offered during the post-connect capability
+        /// handshake, in preference order. See `SessionConfig::compression`.
+        compression: Vec<crate::transport::CompressionKind>,
         respond_to: oneshot::Sender<SessionHandle>,
     },
+    /// Starts an acceptor on `bind_addr` owned by this client: accepted
+    /// sessions are gated against the `expected_sessions` allowlist, and
+    /// their events/`Send`/`SendAdmin` are scoped to this `ClientConnection`
+    /// alongside any sessions it initiated.
+    Listen {
+        bind_addr: SocketAddr,
+        expected_sessions: Vec<(String, String)>,
+        /// Overrides the gateway's configured transport for this acceptor
+        /// only. `None` uses the gateway-wide transport.
+        transport: Option<TransportConfig>,
+        /// Compression codecs this acceptor offers to each connecting
+        /// initiator during the post-connect capability handshake.
+        compression: Vec<crate::transport::CompressionKind>,
+        respond_to: oneshot::Sender<Result<()>>,
+    },
+    /// Queues a raw payload on the session's outbound writer. Responds with
+    /// a `SendAck` rather than blocking: `Accepted`/`Queued` both mean the
+    /// message was queued (the latter additionally emits
+    /// `GatewayEvent::Backpressure`), `Rejected` means the queue was full or
+    /// the session no longer exists and nothing was queued.
     Send {
         session_id: u64,
         payload: Bytes,
+        respond_to: oneshot::Sender<SendAck>,
     },
+    /// Like [`Send`](Self::Send), for a structured admin message.
     SendAdmin {
         session_id: u64,
         msg: AdminMessage,
         sender_comp_id: String,
         target_comp_id: String,
+        respond_to: oneshot::Sender<SendAck>,
+    },
+    /// Raises or lowers a session's compliance-journal capture verbosity at
+    /// runtime, without restarting the session. Silently ignored if
+    /// `session_id` names a session that was never registered.
+    SetTraceLevel {
+        session_id: u64,
+        level: TraceLevel,
+    },
+    /// Backs `Session::flush`: pushes a drain marker onto the session's
+    /// outbound writer and resolves `respond_to` once it's been dequeued,
+    /// i.e. everything queued ahead of it has been written to the
+    /// transport. Resolved immediately if the session no longer exists.
+    Flush {
+        session_id: u64,
+        respond_to: oneshot::Sender<()>,
     },
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct SessionHandle {
     pub session_id: u64,
+    /// Which concrete transport (TCP/TLS/WebSocket) this session negotiated.
+    pub transport: TransportKind,
 }
 
 // Re-export for client module
 pub(crate) use ClientCommand as GatewayClientCommand;
 pub(crate) use GatewayEvent as GatewayToClientEvent;
 pub(crate) use SessionHandle as GatewaySessionHandle;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg_with_seq(seq: u32, poss_dup: bool) -> protocol::FixMessage {
+        let mut msg = protocol::FixMessage::new(FixMsgType::Heartbeat);
+        msg.set_field(34, seq.to_string());
+        if poss_dup {
+            msg.set_field(43, "Y");
+        }
+        msg
+    }
+
+    #[test]
+    fn check_inbound_seq_in_order() {
+        let msg = msg_with_seq(5, false);
+        assert!(matches!(check_inbound_seq(&msg, 5), Some(SeqCheck::InOrder)));
+    }
+
+    #[test]
+    fn check_inbound_seq_detects_gap() {
+        let msg = msg_with_seq(8, false);
+        match check_inbound_seq(&msg, 5) {
+            Some(SeqCheck::Gap { begin, end }) => {
+                assert_eq!(begin, 5);
+                assert_eq!(end, 7);
+            }
+            other => panic!("expected Gap, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_inbound_seq_detects_too_low() {
+        let msg = msg_with_seq(3, false);
+        assert!(matches!(check_inbound_seq(&msg, 5), Some(SeqCheck::TooLow)));
+    }
+
+    #[test]
+    fn check_inbound_seq_exempts_poss_dup_from_gap_and_too_low() {
+        // A PossDup carrying a seq below the expected next would normally be
+        // TooLow, and one above would normally be a Gap -- either must be
+        // treated as InOrder instead, since duplicates are allowed to arrive
+        // out of the normal flow.
+        assert!(matches!(check_inbound_seq(&msg_with_seq(3, true), 5), Some(SeqCheck::InOrder)));
+        assert!(matches!(check_inbound_seq(&msg_with_seq(8, true), 5), Some(SeqCheck::InOrder)));
+    }
+
+    #[test]
+    fn check_inbound_seq_missing_seq_tag_returns_none() {
+        let msg = protocol::FixMessage::new(FixMsgType::Heartbeat);
+        assert!(check_inbound_seq(&msg, 5).is_none());
+    }
+
+    fn sequence_reset(new_seq: u32, gap_fill: bool) -> protocol::FixMessage {
+        let mut msg = protocol::FixMessage::new(FixMsgType::SequenceReset);
+        msg.set_field(36, new_seq.to_string());
+        if gap_fill {
+            msg.set_field(123, "Y");
+        }
+        msg
+    }
+
+    #[test]
+    fn apply_sequence_reset_gap_fill_only_moves_forward() {
+        let mut expected = 10;
+        apply_sequence_reset(&sequence_reset(20, true), &mut expected);
+        assert_eq!(expected, 20);
+
+        // A gap-fill reset to a lower NewSeqNo must not move the expected
+        // sequence backwards.
+        apply_sequence_reset(&sequence_reset(5, true), &mut expected);
+        assert_eq!(expected, 20);
+    }
+
+    #[test]
+    fn apply_sequence_reset_reset_mode_forces_value_in_either_direction() {
+        let mut expected = 10;
+        apply_sequence_reset(&sequence_reset(3, false), &mut expected);
+        assert_eq!(expected, 3, "reset mode (123 absent/not Y) must force NewSeqNo regardless of direction");
+
+        apply_sequence_reset(&sequence_reset(50, false), &mut expected);
+        assert_eq!(expected, 50);
+    }
+
+    #[test]
+    fn apply_sequence_reset_missing_new_seq_no_is_a_no_op() {
+        let mut expected = 10;
+        apply_sequence_reset(&protocol::FixMessage::new(FixMsgType::SequenceReset), &mut expected);
+        assert_eq!(expected, 10);
+    }
+}
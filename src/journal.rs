@@ -0,0 +1,347 @@
+use crate::storage::{Direction, SessionKey};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::{self, Duration};
+
+/// Capture verbosity for a session's compliance journal, adjustable at
+/// runtime via `ClientCommand::SetTraceLevel` without restarting the session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TraceLevel {
+    /// Nothing is journaled.
+    Off,
+    /// Only session-level (administrative) messages are journaled.
+    Admin,
+    /// Every inbound/outbound message is journaled.
+    Full,
+}
+
+impl TraceLevel {
+    fn to_u8(self) -> u8 {
+        match self {
+            TraceLevel::Off => 0,
+            TraceLevel::Admin => 1,
+            TraceLevel::Full => 2,
+        }
+    }
+
+    fn from_u8(v: u8) -> Self {
+        match v {
+            0 => TraceLevel::Off,
+            1 => TraceLevel::Admin,
+            _ => TraceLevel::Full,
+        }
+    }
+}
+
+/// Lock-free, runtime-adjustable holder for one session's [`TraceLevel`],
+/// mirroring `gateway::SessionMeters`'s use of atomics for hot-path state
+/// that's updated from the connection task and read/written from the
+/// command loop without a lock.
+#[derive(Debug)]
+pub struct TraceLevelHandle(AtomicU8);
+
+impl TraceLevelHandle {
+    pub fn get(&self) -> TraceLevel {
+        TraceLevel::from_u8(self.0.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, level: TraceLevel) {
+        self.0.store(level.to_u8(), Ordering::Relaxed);
+    }
+}
+
+impl Default for TraceLevelHandle {
+    fn default() -> Self {
+        Self(AtomicU8::new(TraceLevel::Full.to_u8()))
+    }
+}
+
+/// One journaled FIX message: a durable, replayable record of exactly what
+/// crossed the wire for a session. Kept separate from `MessageStore`, which
+/// persists messages for resend/sequence recovery rather than compliance
+/// audit, so a `JournalConfig` can point a venue's regulated sessions at a
+/// different (or longer-retained) sink without touching resend storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalRecord {
+    pub session: SessionKey,
+    pub direction: Direction,
+    pub seq: Option<u32>,
+    pub msg_type: String,
+    pub ts_millis: u64,
+    pub payload_b64: String,
+}
+
+/// Configuration for the per-session compliance journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JournalConfig {
+    /// Append-only JSON Lines files under `base_dir`, one per session.
+    File { base_dir: PathBuf },
+    /// A single `journal_records` table, keyed on session and time, written
+    /// through batched async inserts. See [`SqlJournalSink`].
+    Sql { database_url: String },
+    /// Journaling disabled entirely.
+    Disabled,
+}
+
+impl Default for JournalConfig {
+    fn default() -> Self {
+        JournalConfig::File {
+            base_dir: PathBuf::from("data/journal/trace"),
+        }
+    }
+}
+
+/// Durable sink for [`JournalRecord`]s, queryable by time range for
+/// compliance replay via `GatewayCommand::ReplayJournal`. Also the
+/// extension point for forwarding the same records elsewhere (e.g. a log
+/// shipper), by providing an alternate implementation in place of
+/// [`FileJournalSink`].
+#[async_trait]
+pub trait JournalSink: Send + Sync {
+    async fn record(&self, rec: JournalRecord) -> std::io::Result<()>;
+    /// Returns every record for `session` with `from_ts <= ts_millis <= to_ts`.
+    async fn query(
+        &self,
+        session: &SessionKey,
+        from_ts: u64,
+        to_ts: u64,
+    ) -> std::io::Result<Vec<JournalRecord>>;
+}
+
+/// No-op sink used when journaling is disabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullJournalSink;
+
+#[async_trait]
+impl JournalSink for NullJournalSink {
+    async fn record(&self, _rec: JournalRecord) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        _session: &SessionKey,
+        _from_ts: u64,
+        _to_ts: u64,
+    ) -> std::io::Result<Vec<JournalRecord>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Appends each record as one JSON line to `<base_dir>/<session>.trace.jsonl`.
+/// Writes are serialized through a `tokio::sync::Mutex` rather than
+/// `FileMessageStore`'s batched background task: the compliance journal is
+/// one append per already-async-dispatched message, not a hot per-byte path,
+/// so the extra batching machinery isn't worth the complexity here.
+#[derive(Debug)]
+pub struct FileJournalSink {
+    base_dir: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl FileJournalSink {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    fn path_for(&self, session: &SessionKey) -> PathBuf {
+        self.base_dir
+            .join(format!("{}.trace.jsonl", session.file_stem()))
+    }
+}
+
+#[async_trait]
+impl JournalSink for FileJournalSink {
+    async fn record(&self, rec: JournalRecord) -> std::io::Result<()> {
+        let _guard = self.write_lock.lock().await;
+        fs::create_dir_all(&self.base_dir).await?;
+        let path = self.path_for(&rec.session);
+        let mut f = OpenOptions::new().create(true).append(true).open(&path).await?;
+        let line = serde_json::to_string(&rec).unwrap();
+        f.write_all(line.as_bytes()).await?;
+        f.write_all(b"\n").await?;
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        session: &SessionKey,
+        from_ts: u64,
+        to_ts: u64,
+    ) -> std::io::Result<Vec<JournalRecord>> {
+        let path = self.path_for(session);
+        let content = match fs::read_to_string(&path).await {
+            Ok(s) => s,
+            Err(e) => {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    return Ok(Vec::new());
+                }
+                return Err(e);
+            }
+        };
+        let mut out = Vec::new();
+        for line in content.lines() {
+            if let Ok(rec) = serde_json::from_str::<JournalRecord>(line) {
+                if rec.ts_millis >= from_ts && rec.ts_millis <= to_ts {
+                    out.push(rec);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+const CREATE_TABLE_SQL: &str = "CREATE TABLE IF NOT EXISTS journal_records (
+    sender_comp_id TEXT NOT NULL,
+    target_comp_id TEXT NOT NULL,
+    direction TEXT NOT NULL,
+    seq INTEGER,
+    msg_type TEXT NOT NULL,
+    ts_millis INTEGER NOT NULL,
+    payload_b64 TEXT NOT NULL
+)";
+
+const CREATE_INDEX_SQL: &str =
+    "CREATE INDEX IF NOT EXISTS idx_journal_records_session_ts
+        ON journal_records (sender_comp_id, target_comp_id, ts_millis)";
+
+/// SQL-backed compliance journal: every record lands in one `journal_records`
+/// table keyed on `(sender_comp_id, target_comp_id, ts_millis)`, inserted
+/// through a bounded channel feeding a background task that batches writes
+/// the same way `FileMessageStore` batches its own; each batch is inserted
+/// inside one transaction, so a burst of messages costs one commit instead
+/// of one per record.
+pub struct SqlJournalSink {
+    tx: mpsc::Sender<JournalRecord>,
+    pool: sqlx::SqlitePool,
+}
+
+impl SqlJournalSink {
+    /// `database_url` is any URL `sqlx::SqlitePool` accepts, e.g.
+    /// `sqlite://journal.db`. The connection is established lazily and the
+    /// schema created on first use, so this never blocks on I/O.
+    pub fn new(database_url: impl Into<String>) -> std::io::Result<Self> {
+        let pool = sqlx::SqlitePool::connect_lazy(&database_url.into())
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+
+        let (tx, mut rx) = mpsc::channel::<JournalRecord>(4096);
+        let bg_pool = pool.clone();
+        tokio::spawn(async move {
+            let _ = sqlx::query(CREATE_TABLE_SQL).execute(&bg_pool).await;
+            let _ = sqlx::query(CREATE_INDEX_SQL).execute(&bg_pool).await;
+
+            let mut queue: Vec<JournalRecord> = Vec::with_capacity(256);
+            let mut ticker = time::interval(Duration::from_millis(50));
+            loop {
+                tokio::select! {
+                    maybe = rx.recv() => {
+                        match maybe {
+                            Some(rec) => queue.push(rec),
+                            None => { flush_sql_batch(&bg_pool, &mut queue).await; break; }
+                        }
+                        if queue.len() >= 256 { flush_sql_batch(&bg_pool, &mut queue).await; }
+                    }
+                    _ = ticker.tick() => {
+                        if !queue.is_empty() { flush_sql_batch(&bg_pool, &mut queue).await; }
+                    }
+                }
+            }
+        });
+
+        Ok(Self { tx, pool })
+    }
+}
+
+async fn flush_sql_batch(pool: &sqlx::SqlitePool, queue: &mut Vec<JournalRecord>) {
+    let Ok(mut tx) = pool.begin().await else {
+        queue.clear();
+        return;
+    };
+    for rec in queue.drain(..) {
+        let direction = match rec.direction {
+            Direction::Inbound => "in",
+            Direction::Outbound => "out",
+        };
+        let _ = sqlx::query(
+            "INSERT INTO journal_records
+                (sender_comp_id, target_comp_id, direction, seq, msg_type, ts_millis, payload_b64)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&rec.session.sender_comp_id)
+        .bind(&rec.session.target_comp_id)
+        .bind(direction)
+        .bind(rec.seq.map(|s| s as i64))
+        .bind(&rec.msg_type)
+        .bind(rec.ts_millis as i64)
+        .bind(&rec.payload_b64)
+        .execute(&mut *tx)
+        .await;
+    }
+    let _ = tx.commit().await;
+}
+
+#[async_trait]
+impl JournalSink for SqlJournalSink {
+    async fn record(&self, rec: JournalRecord) -> std::io::Result<()> {
+        self.tx.send(rec).await.map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "sql journal channel closed")
+        })
+    }
+
+    async fn query(
+        &self,
+        session: &SessionKey,
+        from_ts: u64,
+        to_ts: u64,
+    ) -> std::io::Result<Vec<JournalRecord>> {
+        let rows: Vec<(String, String, String, Option<i64>, String, i64, String)> = sqlx::query_as(
+            "SELECT sender_comp_id, target_comp_id, direction, seq, msg_type, ts_millis, payload_b64
+             FROM journal_records
+             WHERE sender_comp_id = ? AND target_comp_id = ? AND ts_millis >= ? AND ts_millis <= ?
+             ORDER BY ts_millis ASC",
+        )
+        .bind(&session.sender_comp_id)
+        .bind(&session.target_comp_id)
+        .bind(from_ts as i64)
+        .bind(to_ts as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(sender_comp_id, target_comp_id, direction, seq, msg_type, ts_millis, payload_b64)| {
+                    JournalRecord {
+                        session: SessionKey { sender_comp_id, target_comp_id },
+                        direction: if direction == "in" { Direction::Inbound } else { Direction::Outbound },
+                        seq: seq.map(|s| s as u32),
+                        msg_type,
+                        ts_millis: ts_millis as u64,
+                        payload_b64,
+                    }
+                },
+            )
+            .collect())
+    }
+}
+
+/// Builds the `JournalSink` implementation selected by `cfg`.
+pub fn make_journal(cfg: &JournalConfig) -> Arc<dyn JournalSink> {
+    match cfg {
+        JournalConfig::File { base_dir } => Arc::new(FileJournalSink::new(base_dir.clone())),
+        JournalConfig::Sql { database_url } => {
+            Arc::new(SqlJournalSink::new(database_url.clone()).expect("SqlJournalSink init"))
+        }
+        JournalConfig::Disabled => Arc::new(NullJournalSink),
+    }
+}
@@ -4,15 +4,26 @@
 #[cfg(feature = "aeron-ffi")]
 pub mod aeron_ffi;
 pub mod client;
+pub mod codec;
 pub mod config;
+pub mod control;
 pub mod error;
 pub mod gateway;
+pub mod journal;
+pub mod matching;
 pub mod messages;
+pub mod metrics;
 pub mod protocol;
+pub mod sbe;
 pub mod session;
 pub mod storage;
+pub mod transport;
 
-pub use client::{FixClient, FixHandler, InboundMessage};
-pub use config::{FixClientConfig, GatewayConfig};
+pub use client::{EventStream, FixClient, FixHandler, InboundMessage};
+pub use config::{AuthOutcome, AuthStrategy, FixClientConfig, GatewayConfig, LogonRequest};
+pub use control::{ControlRequest, ControlResponse, ControlSessionSummary};
 pub use gateway::{Gateway, GatewayHandle};
-pub use session::{DisconnectReason, Session, SessionConfig};
+pub use journal::{JournalConfig, JournalRecord, TraceLevel};
+pub use metrics::LatencyHistogram;
+pub use session::{DisconnectReason, ReconnectConfig, SendAck, Session, SessionConfig};
+pub use transport::{CompressionKind, TransportConfig, TransportKind};
@@ -0,0 +1,449 @@
+//! Price-time-priority matching engine.
+//!
+//! An [`OrderBook`] holds resting orders for a single symbol on two sides —
+//! bids and asks — each a `BTreeMap<PriceKey, VecDeque<RestingOrder>>` so the
+//! best price on either side is found in O(log n) and orders at the same
+//! price level fill in FIFO (arrival) order. Callers own one `OrderBook` per
+//! symbol (e.g. in a `HashMap<String, OrderBook>`) and call
+//! [`OrderBook::submit`] for each incoming order, turning the returned
+//! [`MatchResult`] into `ExecutionReport`s for the resting and incoming
+//! order owners.
+
+use crate::messages::{ExecType, OrdType, Side};
+use std::collections::{BTreeMap, VecDeque};
+
+/// Fixed-point representation of a price, scaled by [`PRICE_SCALE`], so it
+/// can be used as a `BTreeMap` key (`f64` isn't `Ord`).
+const PRICE_SCALE: f64 = 1e4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct PriceKey(i64);
+
+impl PriceKey {
+    fn from_f64(px: f64) -> Self {
+        PriceKey((px * PRICE_SCALE).round() as i64)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / PRICE_SCALE
+    }
+}
+
+/// An order resting in the book after a partial or zero fill.
+#[derive(Debug, Clone)]
+pub struct RestingOrder {
+    pub cl_ord_id: String,
+    /// SenderCompID of the order's owner, used for self-trade prevention.
+    pub comp_id: String,
+    pub price: f64,
+    pub leaves_qty: i64,
+    /// Monotonically increasing arrival sequence, for diagnostics; within a
+    /// price level the `VecDeque`'s insertion order already enforces FIFO.
+    pub seq: u64,
+}
+
+/// A single match between an incoming order and a resting order, produced by
+/// [`OrderBook::submit`].
+#[derive(Debug, Clone)]
+pub struct Fill {
+    pub incoming_cl_ord_id: String,
+    pub incoming_comp_id: String,
+    pub resting_cl_ord_id: String,
+    pub resting_comp_id: String,
+    pub price: f64,
+    pub qty: i64,
+    /// ExecType to report to the incoming order's owner for this fill.
+    pub incoming_exec_type: ExecType,
+    /// ExecType to report to the resting order's owner for this fill.
+    pub resting_exec_type: ExecType,
+}
+
+/// Policy applied when an incoming order would otherwise match against a
+/// resting order from the same SenderCompID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelfTradePrevention {
+    /// Self-trades are allowed; no special handling.
+    #[default]
+    Allow,
+    /// Cancel the resting order and keep matching the incoming order
+    /// against the next-priority order instead.
+    CancelResting,
+    /// Cancel the incoming order's remaining quantity rather than matching
+    /// it against its own resting order.
+    CancelIncoming,
+}
+
+/// An incoming order to be matched against an [`OrderBook`].
+#[derive(Debug, Clone)]
+pub struct IncomingOrder {
+    pub cl_ord_id: String,
+    /// SenderCompID of the order's owner, used for self-trade prevention.
+    pub comp_id: String,
+    pub side: Side,
+    pub ord_type: OrdType,
+    /// Ignored for `OrdType::Market`.
+    pub price: f64,
+    pub qty: i64,
+}
+
+/// Outcome of [`OrderBook::submit`].
+#[derive(Debug, Clone, Default)]
+pub struct MatchResult {
+    /// Fills produced by this submission, in match order.
+    pub fills: Vec<Fill>,
+    /// Quantity of the incoming order still open after matching, i.e. what
+    /// now rests in the book (0 if it fully filled or its remainder was
+    /// canceled).
+    pub leaves_qty: i64,
+    /// Quantity that could not be filled and was not rested either — either
+    /// a market order's unfilled residual, or incoming quantity canceled by
+    /// `SelfTradePrevention::CancelIncoming`.
+    pub canceled_leaves_qty: i64,
+    /// Resting orders canceled outright by `SelfTradePrevention::CancelResting`
+    /// rather than matched.
+    pub self_trade_cancels: Vec<RestingOrder>,
+}
+
+/// Price-time-priority limit order book for a single symbol.
+#[derive(Debug)]
+pub struct OrderBook {
+    bids: BTreeMap<PriceKey, VecDeque<RestingOrder>>,
+    asks: BTreeMap<PriceKey, VecDeque<RestingOrder>>,
+    self_trade_prevention: SelfTradePrevention,
+    next_seq: u64,
+}
+
+impl Default for OrderBook {
+    fn default() -> Self {
+        Self {
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            self_trade_prevention: SelfTradePrevention::default(),
+            next_seq: 0,
+        }
+    }
+}
+
+impl OrderBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_self_trade_prevention(stp: SelfTradePrevention) -> Self {
+        Self {
+            self_trade_prevention: stp,
+            ..Self::default()
+        }
+    }
+
+    /// Matches `order` against the opposite side of the book, then rests any
+    /// residual limit quantity (market orders never rest; residual market
+    /// quantity is reported via `MatchResult::canceled_leaves_qty`).
+    pub fn submit(&mut self, order: IncomingOrder) -> MatchResult {
+        let mut result = MatchResult::default();
+        let mut leaves = order.qty;
+
+        loop {
+            if leaves == 0 {
+                break;
+            }
+
+            let Some(key) = self.best_opposite_key(order.side) else {
+                break;
+            };
+
+            let crosses = match order.ord_type {
+                OrdType::Market => true,
+                OrdType::Limit => match order.side {
+                    Side::Buy => key.to_f64() <= order.price,
+                    Side::Sell => key.to_f64() >= order.price,
+                },
+            };
+            if !crosses {
+                break;
+            }
+
+            let opposite = self.opposite_side_mut(order.side);
+            let level = opposite.get_mut(&key).expect("best_opposite_key returned a present level");
+
+            let Some(resting) = level.front() else {
+                opposite.remove(&key);
+                continue;
+            };
+
+            if resting.comp_id == order.comp_id {
+                match self.self_trade_prevention {
+                    SelfTradePrevention::Allow => {}
+                    SelfTradePrevention::CancelResting => {
+                        let canceled = level.pop_front().expect("front() just returned Some");
+                        if level.is_empty() {
+                            opposite.remove(&key);
+                        }
+                        result.self_trade_cancels.push(canceled);
+                        continue;
+                    }
+                    SelfTradePrevention::CancelIncoming => {
+                        result.canceled_leaves_qty += leaves;
+                        leaves = 0;
+                        break;
+                    }
+                }
+            }
+
+            let resting = level.front_mut().expect("front() just returned Some above");
+            let match_qty = leaves.min(resting.leaves_qty);
+            resting.leaves_qty -= match_qty;
+            leaves -= match_qty;
+
+            let resting_exec_type = if resting.leaves_qty == 0 {
+                ExecType::Fill
+            } else {
+                ExecType::PartialFill
+            };
+            let incoming_exec_type = if leaves == 0 {
+                ExecType::Fill
+            } else {
+                ExecType::PartialFill
+            };
+
+            result.fills.push(Fill {
+                incoming_cl_ord_id: order.cl_ord_id.clone(),
+                incoming_comp_id: order.comp_id.clone(),
+                resting_cl_ord_id: resting.cl_ord_id.clone(),
+                resting_comp_id: resting.comp_id.clone(),
+                price: key.to_f64(),
+                qty: match_qty,
+                incoming_exec_type,
+                resting_exec_type,
+            });
+
+            if resting.leaves_qty == 0 {
+                level.pop_front();
+                if level.is_empty() {
+                    opposite.remove(&key);
+                }
+            }
+        }
+
+        result.leaves_qty = leaves;
+
+        if leaves > 0 {
+            match order.ord_type {
+                OrdType::Limit => self.rest(order, leaves),
+                OrdType::Market => {
+                    result.canceled_leaves_qty += leaves;
+                    result.leaves_qty = 0;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Removes and returns a still-resting order, e.g. in response to an
+    /// `OrderCancelRequest`. Returns `None` if no such order is resting
+    /// (already fully filled, already canceled, or never existed).
+    pub fn cancel(&mut self, side: Side, price: f64, cl_ord_id: &str) -> Option<RestingOrder> {
+        let key = PriceKey::from_f64(price);
+        let side_map = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+        let level = side_map.get_mut(&key)?;
+        let idx = level.iter().position(|o| o.cl_ord_id == cl_ord_id)?;
+        let removed = level.remove(idx);
+        if level.is_empty() {
+            side_map.remove(&key);
+        }
+        removed
+    }
+
+    fn best_opposite_key(&self, incoming_side: Side) -> Option<PriceKey> {
+        match incoming_side {
+            Side::Buy => self.asks.keys().next().copied(),
+            Side::Sell => self.bids.keys().next_back().copied(),
+        }
+    }
+
+    fn opposite_side_mut(&mut self, incoming_side: Side) -> &mut BTreeMap<PriceKey, VecDeque<RestingOrder>> {
+        match incoming_side {
+            Side::Buy => &mut self.asks,
+            Side::Sell => &mut self.bids,
+        }
+    }
+
+    fn rest(&mut self, order: IncomingOrder, leaves_qty: i64) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        let key = PriceKey::from_f64(order.price);
+        let side_map = match order.side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+        side_map.entry(key).or_default().push_back(RestingOrder {
+            cl_ord_id: order.cl_ord_id,
+            comp_id: order.comp_id,
+            price: order.price,
+            leaves_qty,
+            seq,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(cl_ord_id: &str, comp_id: &str, side: Side, ord_type: OrdType, price: f64, qty: i64) -> IncomingOrder {
+        IncomingOrder {
+            cl_ord_id: cl_ord_id.to_string(),
+            comp_id: comp_id.to_string(),
+            side,
+            ord_type,
+            price,
+            qty,
+        }
+    }
+
+    #[test]
+    fn resting_limit_order_with_no_cross_just_rests() {
+        let mut book = OrderBook::new();
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 100));
+        assert!(result.fills.is_empty());
+        assert_eq!(result.leaves_qty, 100);
+        assert_eq!(result.canceled_leaves_qty, 0);
+    }
+
+    #[test]
+    fn crossing_limit_orders_produce_a_fill_at_resting_price() {
+        let mut book = OrderBook::new();
+        book.submit(order("SELL1", "B", Side::Sell, OrdType::Limit, 10.0, 100));
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.5, 100));
+
+        assert_eq!(result.fills.len(), 1);
+        let fill = &result.fills[0];
+        assert_eq!(fill.price, 10.0, "a match fills at the resting order's price, not the incoming order's");
+        assert_eq!(fill.qty, 100);
+        assert_eq!(fill.incoming_cl_ord_id, "BUY1");
+        assert_eq!(fill.resting_cl_ord_id, "SELL1");
+        assert_eq!(fill.incoming_exec_type, ExecType::Fill);
+        assert_eq!(fill.resting_exec_type, ExecType::Fill);
+        assert_eq!(result.leaves_qty, 0);
+    }
+
+    #[test]
+    fn partial_fill_leaves_remainder_resting() {
+        let mut book = OrderBook::new();
+        book.submit(order("SELL1", "B", Side::Sell, OrdType::Limit, 10.0, 40));
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 100));
+
+        assert_eq!(result.fills.len(), 1);
+        assert_eq!(result.fills[0].qty, 40);
+        assert_eq!(result.fills[0].incoming_exec_type, ExecType::PartialFill);
+        assert_eq!(result.fills[0].resting_exec_type, ExecType::Fill);
+        assert_eq!(result.leaves_qty, 60, "the unfilled 60 of the incoming limit order must rest");
+
+        // Confirm it actually rests: a later matching sell should fill against it.
+        let result2 = book.submit(order("SELL2", "C", Side::Sell, OrdType::Limit, 10.0, 60));
+        assert_eq!(result2.fills.len(), 1);
+        assert_eq!(result2.fills[0].resting_cl_ord_id, "BUY1");
+        assert_eq!(result2.fills[0].qty, 60);
+    }
+
+    #[test]
+    fn price_time_priority_fills_best_price_then_fifo_within_level() {
+        let mut book = OrderBook::new();
+        // Two asks at the same price: first-arrived must fill first.
+        book.submit(order("SELL1", "B", Side::Sell, OrdType::Limit, 10.0, 50));
+        book.submit(order("SELL2", "B", Side::Sell, OrdType::Limit, 10.0, 50));
+        // A better (lower) ask placed after both must still be matched first.
+        book.submit(order("SELL3", "B", Side::Sell, OrdType::Limit, 9.0, 50));
+
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 100));
+
+        assert_eq!(result.fills.len(), 2);
+        assert_eq!(result.fills[0].resting_cl_ord_id, "SELL3", "best price must match first regardless of arrival order");
+        assert_eq!(result.fills[0].qty, 50);
+        assert_eq!(result.fills[1].resting_cl_ord_id, "SELL1", "FIFO within a price level: earliest arrival fills next");
+        assert_eq!(result.fills[1].qty, 50);
+    }
+
+    #[test]
+    fn market_order_matches_until_book_empty_and_cancels_residual() {
+        let mut book = OrderBook::new();
+        book.submit(order("SELL1", "B", Side::Sell, OrdType::Limit, 10.0, 40));
+
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Market, 0.0, 100));
+
+        assert_eq!(result.fills.len(), 1);
+        assert_eq!(result.fills[0].qty, 40);
+        assert_eq!(result.leaves_qty, 0);
+        assert_eq!(result.canceled_leaves_qty, 60, "a market order's unfilled residual must be canceled, not rested");
+    }
+
+    #[test]
+    fn limit_order_does_not_cross_when_price_does_not_reach() {
+        let mut book = OrderBook::new();
+        book.submit(order("SELL1", "B", Side::Sell, OrdType::Limit, 10.0, 100));
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 9.0, 100));
+
+        assert!(result.fills.is_empty());
+        assert_eq!(result.leaves_qty, 100, "the incoming order must rest rather than cross at a worse price");
+    }
+
+    #[test]
+    fn self_trade_prevention_allow_matches_same_comp_id() {
+        let mut book = OrderBook::new();
+        book.submit(order("SELL1", "A", Side::Sell, OrdType::Limit, 10.0, 100));
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 100));
+        assert_eq!(result.fills.len(), 1);
+    }
+
+    #[test]
+    fn self_trade_prevention_cancel_resting_skips_own_order_and_matches_next() {
+        let mut book = OrderBook::with_self_trade_prevention(SelfTradePrevention::CancelResting);
+        book.submit(order("SELL1", "A", Side::Sell, OrdType::Limit, 10.0, 50));
+        book.submit(order("SELL2", "B", Side::Sell, OrdType::Limit, 10.0, 50));
+
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 50));
+
+        assert_eq!(result.self_trade_cancels.len(), 1);
+        assert_eq!(result.self_trade_cancels[0].cl_ord_id, "SELL1");
+        assert_eq!(result.fills.len(), 1);
+        assert_eq!(result.fills[0].resting_cl_ord_id, "SELL2", "matching must continue against the next-priority order");
+    }
+
+    #[test]
+    fn self_trade_prevention_cancel_incoming_cancels_remaining_qty() {
+        let mut book = OrderBook::with_self_trade_prevention(SelfTradePrevention::CancelIncoming);
+        book.submit(order("SELL1", "A", Side::Sell, OrdType::Limit, 10.0, 100));
+
+        let result = book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 100));
+
+        assert!(result.fills.is_empty());
+        assert_eq!(result.leaves_qty, 0);
+        assert_eq!(result.canceled_leaves_qty, 100);
+    }
+
+    #[test]
+    fn cancel_removes_resting_order_and_empties_level() {
+        let mut book = OrderBook::new();
+        book.submit(order("BUY1", "A", Side::Buy, OrdType::Limit, 10.0, 100));
+
+        let removed = book.cancel(Side::Buy, 10.0, "BUY1");
+        assert!(removed.is_some());
+        assert_eq!(removed.unwrap().cl_ord_id, "BUY1");
+
+        // The level must be gone too, not just the order within it -- a
+        // later sell at that price shouldn't find a phantom level to match.
+        let result = book.submit(order("SELL1", "B", Side::Sell, OrdType::Limit, 10.0, 50));
+        assert!(result.fills.is_empty());
+    }
+
+    #[test]
+    fn cancel_unknown_order_returns_none() {
+        let mut book = OrderBook::new();
+        assert!(book.cancel(Side::Buy, 10.0, "NOPE").is_none());
+    }
+}
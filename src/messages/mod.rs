@@ -1,8 +1,115 @@
-use bytes::{Bytes, BytesMut};
+use crate::protocol::{self, FixMessage, FixMsgType};
+use bytes::Bytes;
+use thiserror::Error;
 
 pub mod generated;
 pub use generated::AdminMessage;
 
+/// MsgType(35) value for an ExecutionReport, per the FIX spec.
+const MSG_TYPE_EXECUTION_REPORT: &str = "8";
+/// MsgType(35) value for a NewOrderSingle, per the FIX spec.
+const MSG_TYPE_NEW_ORDER_SINGLE: &str = "D";
+/// MsgType(35) value for an OrderCancelRequest, per the FIX spec.
+const MSG_TYPE_ORDER_CANCEL_REQUEST: &str = "F";
+/// MsgType(35) value for an OrderCancelReplaceRequest, per the FIX spec.
+const MSG_TYPE_ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+/// MsgType(35) value for a MarketDataRequest, per the FIX spec.
+const MSG_TYPE_MARKET_DATA_REQUEST: &str = "V";
+/// MsgType(35) value for a MarketDataSnapshotFullRefresh, per the FIX spec.
+const MSG_TYPE_MARKET_DATA_SNAPSHOT_FULL_REFRESH: &str = "W";
+/// MsgType(35) value for a MarketDataIncrementalRefresh, per the FIX spec.
+const MSG_TYPE_MARKET_DATA_INCREMENTAL_REFRESH: &str = "X";
+
+// Application-level tags used by the order-flow messages below, alongside
+// the session-layer tags `protocol` already knows about.
+const TAG_CL_ORD_ID: u32 = 11;
+const TAG_ORIG_CL_ORD_ID: u32 = 41;
+const TAG_ORDER_ID: u32 = 37;
+const TAG_EXEC_ID: u32 = 17;
+const TAG_SYMBOL: u32 = 55;
+const TAG_SIDE: u32 = 54;
+const TAG_ORDER_QTY: u32 = 38;
+const TAG_PRICE: u32 = 44;
+const TAG_ORD_TYPE: u32 = 40;
+const TAG_EXEC_TYPE: u32 = 150;
+const TAG_ORD_STATUS: u32 = 39;
+const TAG_LAST_PX: u32 = 31;
+const TAG_LAST_QTY: u32 = 32;
+const TAG_LEAVES_QTY: u32 = 151;
+const TAG_CUM_QTY: u32 = 14;
+const TAG_AVG_PX: u32 = 6;
+
+// Market-data tags, used by MarketDataRequest/SnapshotFullRefresh/IncrementalRefresh.
+const TAG_MD_REQ_ID: u32 = 262;
+const TAG_SUBSCRIPTION_REQUEST_TYPE: u32 = 263;
+const TAG_MARKET_DEPTH: u32 = 264;
+/// NoRelatedSym(146): count of symbols in the group below. `protocol`'s codec
+/// only stores one value per tag, so unlike a real repeating group the
+/// symbols themselves travel as a single comma-joined `Symbol`(55) value
+/// rather than `NoRelatedSym` repetitions of tag 55 — this count is kept
+/// for validation, and so a future real-repeating-group codec upgrade has
+/// something to check against.
+const TAG_NO_RELATED_SYM: u32 = 146;
+const TAG_MD_UPDATE_ACTION: u32 = 279;
+const TAG_MD_ENTRY_TYPE: u32 = 269;
+const TAG_MD_ENTRY_PX: u32 = 270;
+const TAG_MD_ENTRY_SIZE: u32 = 271;
+
+fn is_msg_type(msg: &FixMessage, expected: &str) -> bool {
+    matches!(&msg.msg_type, FixMsgType::Unknown(s) if s == expected)
+}
+
+/// Reads a required tag out of a decoded message, for `AppMessage::parse`
+/// implementations.
+fn require_field(msg: &FixMessage, tag: u32) -> Result<String, ParseError> {
+    msg.fields.get(&tag).cloned().ok_or(ParseError::MissingField(tag))
+}
+
+/// Error returned by an [`AppMessage::parse`].
+#[derive(Debug, Error)]
+pub enum ParseError {
+    /// The decoded message's MsgType(35) wasn't the one this type parses.
+    #[error("wrong MsgType(35): expected {expected}, got {actual:?}")]
+    WrongMsgType {
+        expected: &'static str,
+        actual: FixMsgType,
+    },
+    /// A tag required by this message type was absent.
+    #[error("missing required tag {0}")]
+    MissingField(u32),
+    /// The raw bytes didn't decode as a well-formed FIX message at all.
+    #[error("malformed FIX message: {0}")]
+    Malformed(String),
+}
+
+/// Common encode/decode surface for typed application-layer (non-session)
+/// FIX messages. Lets callers like `InboundMessage::as_app` round-trip a
+/// message without hand-rolling tag=value strings or substring-matching the
+/// raw payload for a MsgType(35).
+pub trait AppMessage: Sized {
+    /// MsgType(35) wire value for this message, e.g. `"D"` for NewOrderSingle.
+    const MSG_TYPE: &'static str;
+
+    /// SOH-delimited tag=value encoding of this message, with correct
+    /// BodyLength(9)/CheckSum(10).
+    fn encode(&self) -> Bytes;
+
+    /// Decodes `body` and validates it's a `Self::MSG_TYPE` message with all
+    /// required fields present.
+    fn parse(body: &[u8]) -> Result<Self, ParseError>;
+}
+
+fn parse_checked(body: &[u8], expected_msg_type: &'static str) -> Result<FixMessage, ParseError> {
+    let msg = protocol::decode(body).map_err(ParseError::Malformed)?;
+    if !is_msg_type(&msg, expected_msg_type) {
+        return Err(ParseError::WrongMsgType {
+            expected: expected_msg_type,
+            actual: msg.msg_type,
+        });
+    }
+    Ok(msg)
+}
+
 /// FIX Logon message structure.
 /// 
 /// Represents a FIX Logon message used to initiate a session.
@@ -14,19 +121,99 @@ pub struct Logon;
 /// 
 /// Defines the different ways an order can be executed or processed
 /// in the trading system.
-#[derive(Debug, Clone)]
-pub enum ExecType { 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecType {
+    /// Order accepted by the exchange, no fills yet
+    New,
     /// Order was completely filled
-    Fill 
+    Fill,
+    /// Order was partially filled
+    PartialFill,
+    /// Order was canceled
+    Canceled,
+    /// Order was rejected
+    Rejected,
+    /// A match has been reserved against the order but not yet confirmed;
+    /// the order had no prior fills. Part of the optimistic two-phase
+    /// execution lifecycle — see `OrderManager` in `examples/order_management.rs`.
+    PendingNew,
+    /// A match has been reserved against the order but not yet confirmed;
+    /// the order already had at least one confirmed fill. Not a standard
+    /// FIX ExecType — reuses the unassigned lowercase `a` wire value.
+    PendingFill,
+    /// Order was not completed by the end of the trading day.
+    DoneForDay,
+}
+
+impl ExecType {
+    /// ExecType(150) wire value.
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            ExecType::New => "0",
+            ExecType::PartialFill => "1",
+            ExecType::Fill => "F",
+            ExecType::Canceled => "4",
+            ExecType::Rejected => "8",
+            ExecType::PendingNew => "A",
+            ExecType::PendingFill => "a",
+            ExecType::DoneForDay => "3",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "0" => Some(ExecType::New),
+            "1" => Some(ExecType::PartialFill),
+            "F" => Some(ExecType::Fill),
+            "4" => Some(ExecType::Canceled),
+            "8" => Some(ExecType::Rejected),
+            "A" => Some(ExecType::PendingNew),
+            "a" => Some(ExecType::PendingFill),
+            "3" => Some(ExecType::DoneForDay),
+            _ => None,
+        }
+    }
 }
 
 /// Current status of an order in the trading system.
 /// 
 /// Indicates the current state of an order from submission to completion.
-#[derive(Debug, Clone)]
-pub enum OrdStatus { 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrdStatus {
+    /// Order accepted, no fills yet
+    New,
+    /// Order partially filled, some quantity remains open
+    PartiallyFilled,
     /// Order has been completely filled
-    Filled 
+    Filled,
+    /// Order was canceled
+    Canceled,
+    /// Order was rejected
+    Rejected,
+}
+
+impl OrdStatus {
+    /// OrdStatus(39) wire value.
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            OrdStatus::New => "0",
+            OrdStatus::PartiallyFilled => "1",
+            OrdStatus::Filled => "2",
+            OrdStatus::Canceled => "4",
+            OrdStatus::Rejected => "8",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "0" => Some(OrdStatus::New),
+            "1" => Some(OrdStatus::PartiallyFilled),
+            "2" => Some(OrdStatus::Filled),
+            "4" => Some(OrdStatus::Canceled),
+            "8" => Some(OrdStatus::Rejected),
+            _ => None,
+        }
+    }
 }
 
 /// FIX Execution Report message containing order execution details.
@@ -39,18 +226,67 @@ pub struct ExecutionReport {
     cl_ord_id: String,
     /// Exchange-assigned order identifier
     order_id: String,
+    /// Exchange-assigned identifier for this specific execution, ExecID(17)
+    exec_id: String,
     /// Type of execution that occurred
     exec_type: ExecType,
     /// Current status of the order
     ord_status: OrdStatus,
+    /// Symbol(55) of the order this report reports on
+    symbol: String,
+    /// Side(54) of the order this report reports on
+    side: Side,
     /// Price of the last fill
     last_px: f64,
     /// Quantity of the last fill
     last_qty: i64,
+    /// Quantity still open, LeavesQty(151)
+    leaves_qty: i64,
+    /// Total quantity filled so far, CumQty(14)
+    cum_qty: i64,
+    /// Average fill price across all executions so far, AvgPx(6)
+    avg_px: f64,
 }
 
 impl ExecutionReport {
     pub fn builder() -> ExecutionReportBuilder { ExecutionReportBuilder::default() }
+
+    /// Client-assigned order identifier this report reports on, used to
+    /// correlate it back to the `OrderRequest` that produced it.
+    pub fn cl_ord_id(&self) -> &str { &self.cl_ord_id }
+
+    /// Exchange-assigned order identifier, OrderID(37).
+    pub fn order_id(&self) -> &str { &self.order_id }
+
+    /// ExecID(17).
+    pub fn exec_id(&self) -> &str { &self.exec_id }
+
+    /// ExecType(150).
+    pub fn exec_type(&self) -> &ExecType { &self.exec_type }
+
+    /// OrdStatus(39).
+    pub fn ord_status(&self) -> &OrdStatus { &self.ord_status }
+
+    /// Symbol(55).
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    /// Side(54).
+    pub fn side(&self) -> Side { self.side }
+
+    /// LastPx(31).
+    pub fn last_px(&self) -> f64 { self.last_px }
+
+    /// LastQty(32).
+    pub fn last_qty(&self) -> i64 { self.last_qty }
+
+    /// LeavesQty(151).
+    pub fn leaves_qty(&self) -> i64 { self.leaves_qty }
+
+    /// CumQty(14).
+    pub fn cum_qty(&self) -> i64 { self.cum_qty }
+
+    /// AvgPx(6).
+    pub fn avg_px(&self) -> f64 { self.avg_px }
 }
 
 /// Builder pattern implementation for constructing ExecutionReport instances.
@@ -63,45 +299,1054 @@ pub struct ExecutionReportBuilder {
     cl_ord_id: Option<String>,
     /// Order ID being built
     order_id: Option<String>,
+    /// Exec ID being built
+    exec_id: Option<String>,
     /// Execution type being built
     exec_type: Option<ExecType>,
     /// Order status being built
     ord_status: Option<OrdStatus>,
+    /// Symbol being built
+    symbol: Option<String>,
+    /// Side being built
+    side: Option<Side>,
     /// Last execution price being built
     last_px: Option<f64>,
     /// Last execution quantity being built
     last_qty: Option<i64>,
+    /// Leaves quantity being built
+    leaves_qty: Option<i64>,
+    /// Cumulative quantity being built
+    cum_qty: Option<i64>,
+    /// Average price being built
+    avg_px: Option<f64>,
 }
 
 impl ExecutionReportBuilder {
     pub fn cl_ord_id(mut self, v: impl Into<String>) -> Self { self.cl_ord_id = Some(v.into()); self }
     pub fn order_id(mut self, v: impl Into<String>) -> Self { self.order_id = Some(v.into()); self }
+    pub fn exec_id(mut self, v: impl Into<String>) -> Self { self.exec_id = Some(v.into()); self }
     pub fn exec_type(mut self, v: ExecType) -> Self { self.exec_type = Some(v); self }
     pub fn ord_status(mut self, v: OrdStatus) -> Self { self.ord_status = Some(v); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbol = Some(v.into()); self }
+    pub fn side(mut self, v: Side) -> Self { self.side = Some(v); self }
     pub fn last_px(mut self, v: f64) -> Self { self.last_px = Some(v); self }
     pub fn last_qty(mut self, v: i64) -> Self { self.last_qty = Some(v); self }
+    pub fn leaves_qty(mut self, v: i64) -> Self { self.leaves_qty = Some(v); self }
+    pub fn cum_qty(mut self, v: i64) -> Self { self.cum_qty = Some(v); self }
+    pub fn avg_px(mut self, v: f64) -> Self { self.avg_px = Some(v); self }
 
     pub fn build(self) -> ExecutionReport {
         ExecutionReport {
             cl_ord_id: self.cl_ord_id.unwrap_or_default(),
             order_id: self.order_id.unwrap_or_default(),
+            exec_id: self.exec_id.unwrap_or_default(),
             exec_type: self.exec_type.unwrap_or(ExecType::Fill),
             ord_status: self.ord_status.unwrap_or(OrdStatus::Filled),
+            symbol: self.symbol.unwrap_or_default(),
+            side: self.side.unwrap_or(Side::Buy),
             last_px: self.last_px.unwrap_or_default(),
             last_qty: self.last_qty.unwrap_or_default(),
+            leaves_qty: self.leaves_qty.unwrap_or_default(),
+            cum_qty: self.cum_qty.unwrap_or_default(),
+            avg_px: self.avg_px.unwrap_or_default(),
         }
     }
 }
 
+impl From<ExecutionReport> for FixMessage {
+    fn from(er: ExecutionReport) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_EXECUTION_REPORT.to_string()));
+        msg.set_field(TAG_CL_ORD_ID, er.cl_ord_id);
+        msg.set_field(TAG_ORDER_ID, er.order_id);
+        msg.set_field(TAG_EXEC_ID, er.exec_id);
+        msg.set_field(TAG_EXEC_TYPE, er.exec_type.as_fix_str());
+        msg.set_field(TAG_ORD_STATUS, er.ord_status.as_fix_str());
+        msg.set_field(TAG_SYMBOL, er.symbol);
+        msg.set_field(TAG_SIDE, er.side.as_fix_str());
+        msg.set_field(TAG_LAST_PX, er.last_px.to_string());
+        msg.set_field(TAG_LAST_QTY, er.last_qty.to_string());
+        msg.set_field(TAG_LEAVES_QTY, er.leaves_qty.to_string());
+        msg.set_field(TAG_CUM_QTY, er.cum_qty.to_string());
+        msg.set_field(TAG_AVG_PX, er.avg_px.to_string());
+        msg
+    }
+}
+
 impl From<ExecutionReport> for Bytes {
     fn from(er: ExecutionReport) -> Self {
-        // Placeholder encoding; real impl would encode FIX tags. Here we serialize as a simple string.
-        let mut buf = BytesMut::new();
-        let s = format!(
-            "ExecReport|ClOrdID={}|OrderID={}|LastPx={}|LastQty={}",
-            er.cl_ord_id, er.order_id, er.last_px, er.last_qty
-        );
-        buf.extend_from_slice(s.as_bytes());
-        buf.freeze()
-    }
-}
\ No newline at end of file
+        let msg: FixMessage = er.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for ExecutionReport {
+    type Error = ();
+
+    /// Reads an ExecutionReport(35=8) out of a message already decoded by
+    /// `protocol::decode`, so `FixClient::run` can correlate an inbound
+    /// report back to the `OrderRequest` that produced it without decoding
+    /// the wire bytes twice.
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_EXECUTION_REPORT) {
+            return Err(());
+        }
+        let cl_ord_id = msg.fields.get(&TAG_CL_ORD_ID).cloned().ok_or(())?;
+        let order_id = msg.fields.get(&TAG_ORDER_ID).cloned().unwrap_or_default();
+        let exec_id = msg.fields.get(&TAG_EXEC_ID).cloned().unwrap_or_default();
+        let exec_type = msg
+            .fields
+            .get(&TAG_EXEC_TYPE)
+            .and_then(|v| ExecType::from_fix_str(v))
+            .unwrap_or(ExecType::Fill);
+        let ord_status = msg
+            .fields
+            .get(&TAG_ORD_STATUS)
+            .and_then(|v| OrdStatus::from_fix_str(v))
+            .unwrap_or(OrdStatus::Filled);
+        let symbol = msg.fields.get(&TAG_SYMBOL).cloned().unwrap_or_default();
+        let side = msg
+            .fields
+            .get(&TAG_SIDE)
+            .and_then(|v| Side::from_fix_str(v))
+            .unwrap_or(Side::Buy);
+        let last_px = msg
+            .fields
+            .get(&TAG_LAST_PX)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let last_qty = msg
+            .fields
+            .get(&TAG_LAST_QTY)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let leaves_qty = msg
+            .fields
+            .get(&TAG_LEAVES_QTY)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let cum_qty = msg
+            .fields
+            .get(&TAG_CUM_QTY)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let avg_px = msg
+            .fields
+            .get(&TAG_AVG_PX)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        Ok(ExecutionReport {
+            cl_ord_id,
+            order_id,
+            exec_id,
+            exec_type,
+            ord_status,
+            symbol,
+            side,
+            last_px,
+            last_qty,
+            leaves_qty,
+            cum_qty,
+            avg_px,
+        })
+    }
+}
+
+impl AppMessage for ExecutionReport {
+    const MSG_TYPE: &'static str = MSG_TYPE_EXECUTION_REPORT;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_EXECUTION_REPORT)?;
+        ExecutionReport::try_from(&msg)
+            .map_err(|_| ParseError::MissingField(TAG_CL_ORD_ID))
+    }
+}
+
+/// Side of a `NewOrderSingle`.
+#[derive(Debug, Clone, Copy)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    /// Side(54) wire value.
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            Side::Buy => "1",
+            Side::Sell => "2",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "1" => Some(Side::Buy),
+            "2" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+}
+
+/// OrdType(40) of a `NewOrderSingle`/`OrderCancelReplaceRequest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrdType {
+    Market,
+    Limit,
+}
+
+impl OrdType {
+    /// OrdType(40) wire value.
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            OrdType::Market => "1",
+            OrdType::Limit => "2",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "1" => Some(OrdType::Market),
+            "2" => Some(OrdType::Limit),
+            _ => None,
+        }
+    }
+}
+
+/// Parameters for a new order (NewOrderSingle), the request half of the
+/// `FixClient::send_order`/`ExecutionReport` correlation pair. `cl_ord_id`
+/// must be unique per session so the matching `ExecutionReport` can be
+/// routed back to this request's caller.
+#[derive(Debug, Clone)]
+pub struct OrderRequest {
+    cl_ord_id: String,
+    symbol: String,
+    side: Side,
+    quantity: i64,
+    price: f64,
+    ord_type: OrdType,
+}
+
+impl OrderRequest {
+    pub fn builder() -> OrderRequestBuilder { OrderRequestBuilder::default() }
+
+    pub fn cl_ord_id(&self) -> &str { &self.cl_ord_id }
+
+    /// Symbol(55).
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    /// Side(54).
+    pub fn side(&self) -> Side { self.side }
+
+    /// OrderQty(38).
+    pub fn quantity(&self) -> i64 { self.quantity }
+
+    /// Price(44).
+    pub fn price(&self) -> f64 { self.price }
+
+    /// OrdType(40).
+    pub fn ord_type(&self) -> OrdType { self.ord_type }
+}
+
+/// Builder pattern implementation for constructing OrderRequest instances.
+#[derive(Debug, Default)]
+pub struct OrderRequestBuilder {
+    cl_ord_id: Option<String>,
+    symbol: Option<String>,
+    side: Option<Side>,
+    quantity: Option<i64>,
+    price: Option<f64>,
+    ord_type: Option<OrdType>,
+}
+
+impl OrderRequestBuilder {
+    pub fn cl_ord_id(mut self, v: impl Into<String>) -> Self { self.cl_ord_id = Some(v.into()); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbol = Some(v.into()); self }
+    pub fn side(mut self, v: Side) -> Self { self.side = Some(v); self }
+    pub fn quantity(mut self, v: i64) -> Self { self.quantity = Some(v); self }
+    pub fn price(mut self, v: f64) -> Self { self.price = Some(v); self }
+    pub fn ord_type(mut self, v: OrdType) -> Self { self.ord_type = Some(v); self }
+
+    pub fn build(self) -> OrderRequest {
+        OrderRequest {
+            cl_ord_id: self.cl_ord_id.unwrap_or_default(),
+            symbol: self.symbol.unwrap_or_default(),
+            side: self.side.unwrap_or(Side::Buy),
+            quantity: self.quantity.unwrap_or_default(),
+            price: self.price.unwrap_or_default(),
+            ord_type: self.ord_type.unwrap_or(OrdType::Limit),
+        }
+    }
+}
+
+impl From<OrderRequest> for FixMessage {
+    fn from(order: OrderRequest) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_NEW_ORDER_SINGLE.to_string()));
+        msg.set_field(TAG_CL_ORD_ID, order.cl_ord_id);
+        msg.set_field(TAG_SYMBOL, order.symbol);
+        msg.set_field(TAG_SIDE, order.side.as_fix_str());
+        msg.set_field(TAG_ORDER_QTY, order.quantity.to_string());
+        msg.set_field(TAG_PRICE, order.price.to_string());
+        msg.set_field(TAG_ORD_TYPE, order.ord_type.as_fix_str());
+        msg
+    }
+}
+
+impl From<OrderRequest> for Bytes {
+    fn from(order: OrderRequest) -> Self {
+        let msg: FixMessage = order.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for OrderRequest {
+    type Error = ();
+
+    /// Inverse of `From<OrderRequest> for FixMessage`, for a counterparty
+    /// receiving a NewOrderSingle(35=D).
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_NEW_ORDER_SINGLE) {
+            return Err(());
+        }
+        let cl_ord_id = msg.fields.get(&TAG_CL_ORD_ID).cloned().ok_or(())?;
+        let symbol = msg.fields.get(&TAG_SYMBOL).cloned().unwrap_or_default();
+        let side = msg
+            .fields
+            .get(&TAG_SIDE)
+            .and_then(|v| Side::from_fix_str(v))
+            .unwrap_or(Side::Buy);
+        let quantity = msg
+            .fields
+            .get(&TAG_ORDER_QTY)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let price = msg
+            .fields
+            .get(&TAG_PRICE)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let ord_type = msg
+            .fields
+            .get(&TAG_ORD_TYPE)
+            .and_then(|v| OrdType::from_fix_str(v))
+            .unwrap_or(OrdType::Limit);
+        Ok(OrderRequest {
+            cl_ord_id,
+            symbol,
+            side,
+            quantity,
+            price,
+            ord_type,
+        })
+    }
+}
+
+impl AppMessage for OrderRequest {
+    const MSG_TYPE: &'static str = MSG_TYPE_NEW_ORDER_SINGLE;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_NEW_ORDER_SINGLE)?;
+        OrderRequest::try_from(&msg).map_err(|_| ParseError::MissingField(TAG_CL_ORD_ID))
+    }
+}
+
+/// Request to cancel a previously submitted, still-working order.
+#[derive(Debug, Clone)]
+pub struct OrderCancelRequest {
+    cl_ord_id: String,
+    orig_cl_ord_id: String,
+    symbol: String,
+    side: Side,
+}
+
+impl OrderCancelRequest {
+    pub fn builder() -> OrderCancelRequestBuilder { OrderCancelRequestBuilder::default() }
+
+    /// ClOrdID(11) of this cancel request itself.
+    pub fn cl_ord_id(&self) -> &str { &self.cl_ord_id }
+
+    /// OrigClOrdID(41) of the order being canceled.
+    pub fn orig_cl_ord_id(&self) -> &str { &self.orig_cl_ord_id }
+
+    /// Symbol(55).
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    /// Side(54).
+    pub fn side(&self) -> Side { self.side }
+}
+
+/// Builder pattern implementation for constructing OrderCancelRequest instances.
+#[derive(Debug, Default)]
+pub struct OrderCancelRequestBuilder {
+    cl_ord_id: Option<String>,
+    orig_cl_ord_id: Option<String>,
+    symbol: Option<String>,
+    side: Option<Side>,
+}
+
+impl OrderCancelRequestBuilder {
+    pub fn cl_ord_id(mut self, v: impl Into<String>) -> Self { self.cl_ord_id = Some(v.into()); self }
+    pub fn orig_cl_ord_id(mut self, v: impl Into<String>) -> Self { self.orig_cl_ord_id = Some(v.into()); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbol = Some(v.into()); self }
+    pub fn side(mut self, v: Side) -> Self { self.side = Some(v); self }
+
+    pub fn build(self) -> OrderCancelRequest {
+        OrderCancelRequest {
+            cl_ord_id: self.cl_ord_id.unwrap_or_default(),
+            orig_cl_ord_id: self.orig_cl_ord_id.unwrap_or_default(),
+            symbol: self.symbol.unwrap_or_default(),
+            side: self.side.unwrap_or(Side::Buy),
+        }
+    }
+}
+
+impl From<OrderCancelRequest> for FixMessage {
+    fn from(req: OrderCancelRequest) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_ORDER_CANCEL_REQUEST.to_string()));
+        msg.set_field(TAG_CL_ORD_ID, req.cl_ord_id);
+        msg.set_field(TAG_ORIG_CL_ORD_ID, req.orig_cl_ord_id);
+        msg.set_field(TAG_SYMBOL, req.symbol);
+        msg.set_field(TAG_SIDE, req.side.as_fix_str());
+        msg
+    }
+}
+
+impl From<OrderCancelRequest> for Bytes {
+    fn from(req: OrderCancelRequest) -> Self {
+        let msg: FixMessage = req.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for OrderCancelRequest {
+    type Error = ();
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_ORDER_CANCEL_REQUEST) {
+            return Err(());
+        }
+        let cl_ord_id = msg.fields.get(&TAG_CL_ORD_ID).cloned().ok_or(())?;
+        let orig_cl_ord_id = msg.fields.get(&TAG_ORIG_CL_ORD_ID).cloned().ok_or(())?;
+        let symbol = msg.fields.get(&TAG_SYMBOL).cloned().unwrap_or_default();
+        let side = msg
+            .fields
+            .get(&TAG_SIDE)
+            .and_then(|v| Side::from_fix_str(v))
+            .unwrap_or(Side::Buy);
+        Ok(OrderCancelRequest {
+            cl_ord_id,
+            orig_cl_ord_id,
+            symbol,
+            side,
+        })
+    }
+}
+
+impl AppMessage for OrderCancelRequest {
+    const MSG_TYPE: &'static str = MSG_TYPE_ORDER_CANCEL_REQUEST;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_ORDER_CANCEL_REQUEST)?;
+        OrderCancelRequest::try_from(&msg).map_err(|_| ParseError::MissingField(TAG_ORIG_CL_ORD_ID))
+    }
+}
+
+/// Request to amend the quantity, price, or order type of a previously
+/// submitted, still-working order. The exchange treats this as a
+/// cancel/replace: the original order is pulled and a new one inserted under
+/// `cl_ord_id`, so it gets a fresh position in the book.
+#[derive(Debug, Clone)]
+pub struct OrderCancelReplaceRequest {
+    cl_ord_id: String,
+    orig_cl_ord_id: String,
+    symbol: String,
+    side: Side,
+    order_qty: i64,
+    price: f64,
+    ord_type: OrdType,
+}
+
+impl OrderCancelReplaceRequest {
+    pub fn builder() -> OrderCancelReplaceRequestBuilder { OrderCancelReplaceRequestBuilder::default() }
+
+    /// ClOrdID(11) of this replacement request itself.
+    pub fn cl_ord_id(&self) -> &str { &self.cl_ord_id }
+
+    /// OrigClOrdID(41) of the order being replaced.
+    pub fn orig_cl_ord_id(&self) -> &str { &self.orig_cl_ord_id }
+
+    /// Symbol(55).
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    /// Side(54).
+    pub fn side(&self) -> Side { self.side }
+
+    /// OrderQty(38) of the replacement.
+    pub fn order_qty(&self) -> i64 { self.order_qty }
+
+    /// Price(44) of the replacement.
+    pub fn price(&self) -> f64 { self.price }
+
+    /// OrdType(40) of the replacement.
+    pub fn ord_type(&self) -> OrdType { self.ord_type }
+}
+
+/// Builder pattern implementation for constructing OrderCancelReplaceRequest instances.
+#[derive(Debug, Default)]
+pub struct OrderCancelReplaceRequestBuilder {
+    cl_ord_id: Option<String>,
+    orig_cl_ord_id: Option<String>,
+    symbol: Option<String>,
+    side: Option<Side>,
+    order_qty: Option<i64>,
+    price: Option<f64>,
+    ord_type: Option<OrdType>,
+}
+
+impl OrderCancelReplaceRequestBuilder {
+    pub fn cl_ord_id(mut self, v: impl Into<String>) -> Self { self.cl_ord_id = Some(v.into()); self }
+    pub fn orig_cl_ord_id(mut self, v: impl Into<String>) -> Self { self.orig_cl_ord_id = Some(v.into()); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbol = Some(v.into()); self }
+    pub fn side(mut self, v: Side) -> Self { self.side = Some(v); self }
+    pub fn order_qty(mut self, v: i64) -> Self { self.order_qty = Some(v); self }
+    pub fn price(mut self, v: f64) -> Self { self.price = Some(v); self }
+    pub fn ord_type(mut self, v: OrdType) -> Self { self.ord_type = Some(v); self }
+
+    pub fn build(self) -> OrderCancelReplaceRequest {
+        OrderCancelReplaceRequest {
+            cl_ord_id: self.cl_ord_id.unwrap_or_default(),
+            orig_cl_ord_id: self.orig_cl_ord_id.unwrap_or_default(),
+            symbol: self.symbol.unwrap_or_default(),
+            side: self.side.unwrap_or(Side::Buy),
+            order_qty: self.order_qty.unwrap_or_default(),
+            price: self.price.unwrap_or_default(),
+            ord_type: self.ord_type.unwrap_or(OrdType::Limit),
+        }
+    }
+}
+
+impl From<OrderCancelReplaceRequest> for FixMessage {
+    fn from(req: OrderCancelReplaceRequest) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_ORDER_CANCEL_REPLACE_REQUEST.to_string()));
+        msg.set_field(TAG_CL_ORD_ID, req.cl_ord_id);
+        msg.set_field(TAG_ORIG_CL_ORD_ID, req.orig_cl_ord_id);
+        msg.set_field(TAG_SYMBOL, req.symbol);
+        msg.set_field(TAG_SIDE, req.side.as_fix_str());
+        msg.set_field(TAG_ORDER_QTY, req.order_qty.to_string());
+        msg.set_field(TAG_PRICE, req.price.to_string());
+        msg.set_field(TAG_ORD_TYPE, req.ord_type.as_fix_str());
+        msg
+    }
+}
+
+impl From<OrderCancelReplaceRequest> for Bytes {
+    fn from(req: OrderCancelReplaceRequest) -> Self {
+        let msg: FixMessage = req.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for OrderCancelReplaceRequest {
+    type Error = ();
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_ORDER_CANCEL_REPLACE_REQUEST) {
+            return Err(());
+        }
+        let cl_ord_id = msg.fields.get(&TAG_CL_ORD_ID).cloned().ok_or(())?;
+        let orig_cl_ord_id = msg.fields.get(&TAG_ORIG_CL_ORD_ID).cloned().ok_or(())?;
+        let symbol = msg.fields.get(&TAG_SYMBOL).cloned().unwrap_or_default();
+        let side = msg
+            .fields
+            .get(&TAG_SIDE)
+            .and_then(|v| Side::from_fix_str(v))
+            .unwrap_or(Side::Buy);
+        let order_qty = msg
+            .fields
+            .get(&TAG_ORDER_QTY)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let price = msg
+            .fields
+            .get(&TAG_PRICE)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let ord_type = msg
+            .fields
+            .get(&TAG_ORD_TYPE)
+            .and_then(|v| OrdType::from_fix_str(v))
+            .unwrap_or(OrdType::Limit);
+        Ok(OrderCancelReplaceRequest {
+            cl_ord_id,
+            orig_cl_ord_id,
+            symbol,
+            side,
+            order_qty,
+            price,
+            ord_type,
+        })
+    }
+}
+
+impl AppMessage for OrderCancelReplaceRequest {
+    const MSG_TYPE: &'static str = MSG_TYPE_ORDER_CANCEL_REPLACE_REQUEST;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_ORDER_CANCEL_REPLACE_REQUEST)?;
+        OrderCancelReplaceRequest::try_from(&msg)
+            .map_err(|_| ParseError::MissingField(TAG_ORIG_CL_ORD_ID))
+    }
+}
+
+/// SubscriptionRequestType(263) of a `MarketDataRequest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionRequestType {
+    /// One-time snapshot, no further updates.
+    Snapshot,
+    /// Initial snapshot followed by incremental updates until unsubscribed.
+    SnapshotPlusUpdates,
+    /// Cancels a previous snapshot-plus-updates subscription.
+    Unsubscribe,
+}
+
+impl SubscriptionRequestType {
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            SubscriptionRequestType::Snapshot => "0",
+            SubscriptionRequestType::SnapshotPlusUpdates => "1",
+            SubscriptionRequestType::Unsubscribe => "2",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "0" => Some(SubscriptionRequestType::Snapshot),
+            "1" => Some(SubscriptionRequestType::SnapshotPlusUpdates),
+            "2" => Some(SubscriptionRequestType::Unsubscribe),
+            _ => None,
+        }
+    }
+}
+
+/// Request to subscribe (or unsubscribe) to market data for one or more
+/// symbols, MarketDataRequest(35=V).
+#[derive(Debug, Clone)]
+pub struct MarketDataRequest {
+    md_req_id: String,
+    subscription_request_type: SubscriptionRequestType,
+    market_depth: i32,
+    symbols: Vec<String>,
+}
+
+impl MarketDataRequest {
+    pub fn builder() -> MarketDataRequestBuilder { MarketDataRequestBuilder::default() }
+
+    /// MDReqID(262).
+    pub fn md_req_id(&self) -> &str { &self.md_req_id }
+
+    /// SubscriptionRequestType(263).
+    pub fn subscription_request_type(&self) -> SubscriptionRequestType { self.subscription_request_type }
+
+    /// MarketDepth(264). 0 means full book.
+    pub fn market_depth(&self) -> i32 { self.market_depth }
+
+    /// Symbols in the NoRelatedSym(146) group.
+    pub fn symbols(&self) -> &[String] { &self.symbols }
+}
+
+/// Builder pattern implementation for constructing MarketDataRequest instances.
+#[derive(Debug, Default)]
+pub struct MarketDataRequestBuilder {
+    md_req_id: Option<String>,
+    subscription_request_type: Option<SubscriptionRequestType>,
+    market_depth: Option<i32>,
+    symbols: Vec<String>,
+}
+
+impl MarketDataRequestBuilder {
+    pub fn md_req_id(mut self, v: impl Into<String>) -> Self { self.md_req_id = Some(v.into()); self }
+    pub fn subscription_request_type(mut self, v: SubscriptionRequestType) -> Self { self.subscription_request_type = Some(v); self }
+    pub fn market_depth(mut self, v: i32) -> Self { self.market_depth = Some(v); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbols.push(v.into()); self }
+    pub fn symbols(mut self, v: impl IntoIterator<Item = String>) -> Self { self.symbols.extend(v); self }
+
+    pub fn build(self) -> MarketDataRequest {
+        MarketDataRequest {
+            md_req_id: self.md_req_id.unwrap_or_default(),
+            subscription_request_type: self.subscription_request_type.unwrap_or(SubscriptionRequestType::Snapshot),
+            market_depth: self.market_depth.unwrap_or_default(),
+            symbols: self.symbols,
+        }
+    }
+}
+
+impl From<MarketDataRequest> for FixMessage {
+    fn from(req: MarketDataRequest) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_MARKET_DATA_REQUEST.to_string()));
+        msg.set_field(TAG_MD_REQ_ID, req.md_req_id);
+        msg.set_field(TAG_SUBSCRIPTION_REQUEST_TYPE, req.subscription_request_type.as_fix_str());
+        msg.set_field(TAG_MARKET_DEPTH, req.market_depth.to_string());
+        msg.set_field(TAG_NO_RELATED_SYM, req.symbols.len().to_string());
+        msg.set_field(TAG_SYMBOL, req.symbols.join(","));
+        msg
+    }
+}
+
+impl From<MarketDataRequest> for Bytes {
+    fn from(req: MarketDataRequest) -> Self {
+        let msg: FixMessage = req.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for MarketDataRequest {
+    type Error = ();
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_MARKET_DATA_REQUEST) {
+            return Err(());
+        }
+        let md_req_id = msg.fields.get(&TAG_MD_REQ_ID).cloned().ok_or(())?;
+        let subscription_request_type = msg
+            .fields
+            .get(&TAG_SUBSCRIPTION_REQUEST_TYPE)
+            .and_then(|v| SubscriptionRequestType::from_fix_str(v))
+            .unwrap_or(SubscriptionRequestType::Snapshot);
+        let market_depth = msg
+            .fields
+            .get(&TAG_MARKET_DEPTH)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let symbols = msg
+            .fields
+            .get(&TAG_SYMBOL)
+            .map(|v| v.split(',').filter(|s| !s.is_empty()).map(str::to_string).collect())
+            .unwrap_or_default();
+        Ok(MarketDataRequest {
+            md_req_id,
+            subscription_request_type,
+            market_depth,
+            symbols,
+        })
+    }
+}
+
+impl AppMessage for MarketDataRequest {
+    const MSG_TYPE: &'static str = MSG_TYPE_MARKET_DATA_REQUEST;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_MARKET_DATA_REQUEST)?;
+        MarketDataRequest::try_from(&msg).map_err(|_| ParseError::MissingField(TAG_MD_REQ_ID))
+    }
+}
+
+/// MDEntryType(269) of a market data entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MDEntryType {
+    Bid,
+    Offer,
+}
+
+impl MDEntryType {
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            MDEntryType::Bid => "0",
+            MDEntryType::Offer => "1",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "0" => Some(MDEntryType::Bid),
+            "1" => Some(MDEntryType::Offer),
+            _ => None,
+        }
+    }
+}
+
+/// One price level of a `MarketDataSnapshotFullRefresh`.
+#[derive(Debug, Clone, Copy)]
+pub struct MDEntry {
+    pub entry_type: MDEntryType,
+    pub px: f64,
+    pub size: i64,
+}
+
+impl MDEntry {
+    fn to_wire(self) -> String {
+        format!("{}:{}:{}", self.entry_type.as_fix_str(), self.px, self.size)
+    }
+
+    fn from_wire(s: &str) -> Option<Self> {
+        let mut parts = s.splitn(3, ':');
+        let entry_type = MDEntryType::from_fix_str(parts.next()?)?;
+        let px = parts.next()?.parse().ok()?;
+        let size = parts.next()?.parse().ok()?;
+        Some(MDEntry { entry_type, px, size })
+    }
+}
+
+/// Full depth snapshot for one symbol, MarketDataSnapshotFullRefresh(35=W),
+/// sent in response to a `SubscriptionRequestType::SnapshotPlusUpdates` (or
+/// plain `Snapshot`) `MarketDataRequest`.
+#[derive(Debug, Clone)]
+pub struct MarketDataSnapshotFullRefresh {
+    md_req_id: String,
+    symbol: String,
+    entries: Vec<MDEntry>,
+}
+
+impl MarketDataSnapshotFullRefresh {
+    pub fn builder() -> MarketDataSnapshotFullRefreshBuilder { MarketDataSnapshotFullRefreshBuilder::default() }
+
+    /// MDReqID(262) of the request this snapshot answers.
+    pub fn md_req_id(&self) -> &str { &self.md_req_id }
+
+    /// Symbol(55).
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    /// NoMDEntries(268) group: one entry per resting price level.
+    pub fn entries(&self) -> &[MDEntry] { &self.entries }
+}
+
+/// Builder pattern implementation for constructing MarketDataSnapshotFullRefresh instances.
+#[derive(Debug, Default)]
+pub struct MarketDataSnapshotFullRefreshBuilder {
+    md_req_id: Option<String>,
+    symbol: Option<String>,
+    entries: Vec<MDEntry>,
+}
+
+impl MarketDataSnapshotFullRefreshBuilder {
+    pub fn md_req_id(mut self, v: impl Into<String>) -> Self { self.md_req_id = Some(v.into()); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbol = Some(v.into()); self }
+    pub fn entry(mut self, v: MDEntry) -> Self { self.entries.push(v); self }
+
+    pub fn build(self) -> MarketDataSnapshotFullRefresh {
+        MarketDataSnapshotFullRefresh {
+            md_req_id: self.md_req_id.unwrap_or_default(),
+            symbol: self.symbol.unwrap_or_default(),
+            entries: self.entries,
+        }
+    }
+}
+
+impl From<MarketDataSnapshotFullRefresh> for FixMessage {
+    fn from(snap: MarketDataSnapshotFullRefresh) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_MARKET_DATA_SNAPSHOT_FULL_REFRESH.to_string()));
+        msg.set_field(TAG_MD_REQ_ID, snap.md_req_id);
+        msg.set_field(TAG_SYMBOL, snap.symbol);
+        let entries: Vec<String> = snap.entries.iter().map(|e| e.to_wire()).collect();
+        msg.set_field(TAG_MD_ENTRY_TYPE, entries.join(";"));
+        msg
+    }
+}
+
+impl From<MarketDataSnapshotFullRefresh> for Bytes {
+    fn from(snap: MarketDataSnapshotFullRefresh) -> Self {
+        let msg: FixMessage = snap.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for MarketDataSnapshotFullRefresh {
+    type Error = ();
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_MARKET_DATA_SNAPSHOT_FULL_REFRESH) {
+            return Err(());
+        }
+        let md_req_id = msg.fields.get(&TAG_MD_REQ_ID).cloned().unwrap_or_default();
+        let symbol = msg.fields.get(&TAG_SYMBOL).cloned().ok_or(())?;
+        let entries = msg
+            .fields
+            .get(&TAG_MD_ENTRY_TYPE)
+            .map(|v| v.split(';').filter(|s| !s.is_empty()).filter_map(MDEntry::from_wire).collect())
+            .unwrap_or_default();
+        Ok(MarketDataSnapshotFullRefresh {
+            md_req_id,
+            symbol,
+            entries,
+        })
+    }
+}
+
+impl AppMessage for MarketDataSnapshotFullRefresh {
+    const MSG_TYPE: &'static str = MSG_TYPE_MARKET_DATA_SNAPSHOT_FULL_REFRESH;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_MARKET_DATA_SNAPSHOT_FULL_REFRESH)?;
+        MarketDataSnapshotFullRefresh::try_from(&msg).map_err(|_| ParseError::MissingField(TAG_SYMBOL))
+    }
+}
+
+/// MDUpdateAction(279) of a `MarketDataIncrementalRefresh` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MDUpdateAction {
+    New,
+    Change,
+    Delete,
+}
+
+impl MDUpdateAction {
+    fn as_fix_str(&self) -> &'static str {
+        match self {
+            MDUpdateAction::New => "0",
+            MDUpdateAction::Change => "1",
+            MDUpdateAction::Delete => "2",
+        }
+    }
+
+    fn from_fix_str(s: &str) -> Option<Self> {
+        match s {
+            "0" => Some(MDUpdateAction::New),
+            "1" => Some(MDUpdateAction::Change),
+            "2" => Some(MDUpdateAction::Delete),
+            _ => None,
+        }
+    }
+}
+
+/// A single depth-of-book delta for one symbol, MarketDataIncrementalRefresh(35=X),
+/// sent to subscribers of that symbol after the initial snapshot.
+#[derive(Debug, Clone)]
+pub struct MarketDataIncrementalRefresh {
+    md_req_id: String,
+    symbol: String,
+    update_action: MDUpdateAction,
+    entry: MDEntry,
+}
+
+impl MarketDataIncrementalRefresh {
+    pub fn builder() -> MarketDataIncrementalRefreshBuilder { MarketDataIncrementalRefreshBuilder::default() }
+
+    /// MDReqID(262) of the subscription this update belongs to.
+    pub fn md_req_id(&self) -> &str { &self.md_req_id }
+
+    /// Symbol(55).
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    /// MDUpdateAction(279).
+    pub fn update_action(&self) -> MDUpdateAction { self.update_action }
+
+    /// The single updated price level.
+    pub fn entry(&self) -> MDEntry { self.entry }
+}
+
+/// Builder pattern implementation for constructing MarketDataIncrementalRefresh instances.
+#[derive(Debug, Default)]
+pub struct MarketDataIncrementalRefreshBuilder {
+    md_req_id: Option<String>,
+    symbol: Option<String>,
+    update_action: Option<MDUpdateAction>,
+    entry: Option<MDEntry>,
+}
+
+impl MarketDataIncrementalRefreshBuilder {
+    pub fn md_req_id(mut self, v: impl Into<String>) -> Self { self.md_req_id = Some(v.into()); self }
+    pub fn symbol(mut self, v: impl Into<String>) -> Self { self.symbol = Some(v.into()); self }
+    pub fn update_action(mut self, v: MDUpdateAction) -> Self { self.update_action = Some(v); self }
+    pub fn entry(mut self, v: MDEntry) -> Self { self.entry = Some(v); self }
+
+    pub fn build(self) -> MarketDataIncrementalRefresh {
+        MarketDataIncrementalRefresh {
+            md_req_id: self.md_req_id.unwrap_or_default(),
+            symbol: self.symbol.unwrap_or_default(),
+            update_action: self.update_action.unwrap_or(MDUpdateAction::New),
+            entry: self.entry.unwrap_or(MDEntry { entry_type: MDEntryType::Bid, px: 0.0, size: 0 }),
+        }
+    }
+}
+
+impl From<MarketDataIncrementalRefresh> for FixMessage {
+    fn from(inc: MarketDataIncrementalRefresh) -> Self {
+        let mut msg = FixMessage::new(FixMsgType::Unknown(MSG_TYPE_MARKET_DATA_INCREMENTAL_REFRESH.to_string()));
+        msg.set_field(TAG_MD_REQ_ID, inc.md_req_id);
+        msg.set_field(TAG_SYMBOL, inc.symbol);
+        msg.set_field(TAG_MD_UPDATE_ACTION, inc.update_action.as_fix_str());
+        msg.set_field(TAG_MD_ENTRY_TYPE, inc.entry.entry_type.as_fix_str());
+        msg.set_field(TAG_MD_ENTRY_PX, inc.entry.px.to_string());
+        msg.set_field(TAG_MD_ENTRY_SIZE, inc.entry.size.to_string());
+        msg
+    }
+}
+
+impl From<MarketDataIncrementalRefresh> for Bytes {
+    fn from(inc: MarketDataIncrementalRefresh) -> Self {
+        let msg: FixMessage = inc.into();
+        protocol::encode(&msg).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&FixMessage> for MarketDataIncrementalRefresh {
+    type Error = ();
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if !is_msg_type(msg, MSG_TYPE_MARKET_DATA_INCREMENTAL_REFRESH) {
+            return Err(());
+        }
+        let md_req_id = msg.fields.get(&TAG_MD_REQ_ID).cloned().unwrap_or_default();
+        let symbol = msg.fields.get(&TAG_SYMBOL).cloned().ok_or(())?;
+        let update_action = msg
+            .fields
+            .get(&TAG_MD_UPDATE_ACTION)
+            .and_then(|v| MDUpdateAction::from_fix_str(v))
+            .unwrap_or(MDUpdateAction::New);
+        let entry_type = msg
+            .fields
+            .get(&TAG_MD_ENTRY_TYPE)
+            .and_then(|v| MDEntryType::from_fix_str(v))
+            .unwrap_or(MDEntryType::Bid);
+        let px = msg
+            .fields
+            .get(&TAG_MD_ENTRY_PX)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let size = msg
+            .fields
+            .get(&TAG_MD_ENTRY_SIZE)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        Ok(MarketDataIncrementalRefresh {
+            md_req_id,
+            symbol,
+            update_action,
+            entry: MDEntry { entry_type, px, size },
+        })
+    }
+}
+
+impl AppMessage for MarketDataIncrementalRefresh {
+    const MSG_TYPE: &'static str = MSG_TYPE_MARKET_DATA_INCREMENTAL_REFRESH;
+
+    fn encode(&self) -> Bytes {
+        self.clone().into()
+    }
+
+    fn parse(body: &[u8]) -> Result<Self, ParseError> {
+        let msg = parse_checked(body, MSG_TYPE_MARKET_DATA_INCREMENTAL_REFRESH)?;
+        MarketDataIncrementalRefresh::try_from(&msg).map_err(|_| ParseError::MissingField(TAG_SYMBOL))
+    }
+}
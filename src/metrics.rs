@@ -0,0 +1,117 @@
+//! Latency percentile tracking for hot-path round-trip timing.
+//!
+//! [`LatencyHistogram`] is a small HDR-style histogram: values are recorded
+//! into a fixed number of logarithmically-spaced buckets spanning
+//! [`LatencyHistogram::MIN_NS`]..[`LatencyHistogram::MAX_NS`], so memory
+//! stays bounded (one `AtomicU64` per bucket) regardless of how many samples
+//! are recorded, while `p50`/`p90`/`p99` stay close to exact -- unlike
+//! `SessionMeters`' prior sum-of-latency-and-max, which hides tail behavior
+//! entirely.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A lock-free latency histogram covering 1µs-60s with a configurable
+/// number of significant decimal digits (default 3, i.e. ~0.2% bucket
+/// width). `record` is a single relaxed `fetch_add` per call, safe to
+/// call from the connection task's hot path without blocking it; `p50` etc.
+/// read the same atomics, so a concurrent `record` can only ever make a
+/// query's answer stale by one sample, never torn.
+#[derive(Debug)]
+pub struct LatencyHistogram {
+    /// `buckets[i]` counts samples whose value fell in
+    /// `[bucket_lower_bound(i), bucket_lower_bound(i + 1))`.
+    buckets: Vec<AtomicU64>,
+    buckets_per_octave: f64,
+    max_ns: AtomicU64,
+}
+
+impl LatencyHistogram {
+    /// Lower bound of the tracked range: 1 microsecond.
+    pub const MIN_NS: u64 = 1_000;
+    /// Upper bound of the tracked range: 60 seconds. A sample above this is
+    /// clamped into the top bucket rather than dropped, so `max`/`p999`
+    /// still reflect that an outlier happened.
+    pub const MAX_NS: u64 = 60_000_000_000;
+
+    /// `significant_digits` controls bucket resolution: each power-of-two
+    /// octave is split into `10^significant_digits / 3` buckets, so with 3
+    /// digits (333 buckets per octave) each bucket spans ~0.2% of its lower
+    /// bound. 3 is a reasonable default for microsecond-to-second latencies.
+    pub fn new(significant_digits: u32) -> Self {
+        let buckets_per_octave = (10f64.powi(significant_digits as i32) / 3.0).max(1.0);
+        let octaves = (Self::MAX_NS as f64 / Self::MIN_NS as f64).log2();
+        let bucket_count = (octaves * buckets_per_octave).ceil() as usize + 1;
+        Self {
+            buckets: (0..bucket_count).map(|_| AtomicU64::new(0)).collect(),
+            buckets_per_octave,
+            max_ns: AtomicU64::new(0),
+        }
+    }
+
+    fn bucket_index(&self, value_ns: u64) -> usize {
+        let clamped = value_ns.clamp(Self::MIN_NS, Self::MAX_NS);
+        let octave = (clamped as f64 / Self::MIN_NS as f64).log2();
+        ((octave * self.buckets_per_octave) as usize).min(self.buckets.len() - 1)
+    }
+
+    fn bucket_lower_bound_ns(&self, index: usize) -> u64 {
+        let octave = index as f64 / self.buckets_per_octave;
+        (Self::MIN_NS as f64 * 2f64.powf(octave)) as u64
+    }
+
+    /// Records one latency sample, in nanoseconds.
+    pub fn record(&self, value_ns: u64) {
+        let idx = self.bucket_index(value_ns);
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.max_ns.fetch_max(value_ns, Ordering::Relaxed);
+    }
+
+    /// Total number of samples recorded.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|b| b.load(Ordering::Relaxed)).sum()
+    }
+
+    /// The largest value ever recorded, in nanoseconds.
+    pub fn max(&self) -> u64 {
+        self.max_ns.load(Ordering::Relaxed)
+    }
+
+    /// Lower bound of the bucket holding the `p`th-percentile sample
+    /// (`p` in `0.0..=1.0`), in nanoseconds, so at most one bucket width
+    /// below the exact value. `0` if nothing's been recorded.
+    pub fn percentile(&self, p: f64) -> u64 {
+        let total = self.count();
+        if total == 0 {
+            return 0;
+        }
+        let target = ((p * total as f64).ceil() as u64).max(1);
+        let mut cumulative = 0u64;
+        for (idx, bucket) in self.buckets.iter().enumerate() {
+            cumulative += bucket.load(Ordering::Relaxed);
+            if cumulative >= target {
+                return self.bucket_lower_bound_ns(idx);
+            }
+        }
+        self.max()
+    }
+
+    pub fn p50(&self) -> u64 {
+        self.percentile(0.50)
+    }
+    pub fn p90(&self) -> u64 {
+        self.percentile(0.90)
+    }
+    pub fn p99(&self) -> u64 {
+        self.percentile(0.99)
+    }
+    pub fn p999(&self) -> u64 {
+        self.percentile(0.999)
+    }
+}
+
+impl Default for LatencyHistogram {
+    /// 3 significant digits, matching the `new` doc comment's default.
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
@@ -0,0 +1,239 @@
+//! Schema-driven binary (SBE-style) encoding for [`FixMessage`], as an
+//! alternative to the tag/value ASCII path in [`crate::protocol`].
+//!
+//! The ASCII codec allocates and UTF-8-parses every field on every message,
+//! which dominates latency on a hot market-data feed. This module instead
+//! writes a fixed message header followed by a fixed-offset "root block" of
+//! scalar fields (so they can be read directly out of a `Bytes` via
+//! `Buf::get_u32_le`/`get_u64_le`, with no parsing or `HashMap` churn), with
+//! any remaining string fields appended length-prefixed afterward. A
+//! [`SbeTemplate`] describes a `template_id`'s field offsets/types, so the
+//! same buffer can be decoded without copying the root block out first.
+
+use crate::protocol::{FixMessage, FixMsgType};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+/// Size in bytes of the fixed SBE message header (`block_length`,
+/// `template_id`, `schema_id`, `version`, each a little-endian `u16`).
+pub const SBE_HEADER_LEN: usize = 8;
+
+/// How a [`SbeScalarField`]'s value is packed into the root block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SbeScalarType {
+    /// A single byte, used for MsgType(35).
+    U8,
+    /// Little-endian `u32`, used for sequence numbers.
+    U32Le,
+    /// Little-endian `u64`, used for millisecond timestamps.
+    U64Le,
+}
+
+impl SbeScalarType {
+    /// Size in bytes this type occupies in the root block.
+    fn width(self) -> usize {
+        match self {
+            SbeScalarType::U8 => 1,
+            SbeScalarType::U32Le => 4,
+            SbeScalarType::U64Le => 8,
+        }
+    }
+}
+
+/// A scalar field packed at a fixed offset in a [`SbeTemplate`]'s root block.
+#[derive(Debug, Clone, Copy)]
+pub struct SbeScalarField {
+    /// FIX tag this field corresponds to. `35` (MsgType) is handled
+    /// specially: its `u8` value is the first byte of the tag's wire string
+    /// (e.g. `b'0'` for Heartbeat, `b'8'` for ExecutionReport) rather than a
+    /// parsed integer.
+    pub tag: u32,
+    pub offset: u16,
+    pub ty: SbeScalarType,
+}
+
+/// Describes one `template_id`'s wire layout: which fields are packed into
+/// the fixed-offset root block, and which remaining fields follow it,
+/// length-prefixed, in order.
+#[derive(Debug, Clone)]
+pub struct SbeTemplate {
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+    /// Total size of the root block in bytes. Must be at least as large as
+    /// the highest `offset + width()` among `scalars`.
+    pub block_length: u16,
+    /// Fixed-offset scalar fields packed into the root block.
+    pub scalars: Vec<SbeScalarField>,
+    /// Remaining fields, written length-prefixed (a `u16` length followed by
+    /// UTF-8 bytes) after the root block, in this order.
+    pub variable_fields: Vec<u32>,
+}
+
+fn msg_type_byte(mt: &FixMsgType) -> u8 {
+    let s = match mt {
+        FixMsgType::Logon => "A",
+        FixMsgType::Heartbeat => "0",
+        FixMsgType::TestRequest => "1",
+        FixMsgType::Logout => "5",
+        FixMsgType::ResendRequest => "2",
+        FixMsgType::SequenceReset => "4",
+        FixMsgType::Unknown(s) => s.as_str(),
+    };
+    s.bytes().next().unwrap_or(b'?')
+}
+
+fn byte_to_msg_type(b: u8) -> FixMsgType {
+    match b {
+        b'A' => FixMsgType::Logon,
+        b'0' => FixMsgType::Heartbeat,
+        b'1' => FixMsgType::TestRequest,
+        b'5' => FixMsgType::Logout,
+        b'2' => FixMsgType::ResendRequest,
+        b'4' => FixMsgType::SequenceReset,
+        other => FixMsgType::Unknown((other as char).to_string()),
+    }
+}
+
+/// Encodes `msg` per `template`: an 8-byte header, a `template.block_length`
+/// root block with each of `template.scalars` written at its offset, then
+/// each of `template.variable_fields` appended length-prefixed.
+pub fn encode_sbe(msg: &FixMessage, template: &SbeTemplate) -> Bytes {
+    let mut out = BytesMut::with_capacity(
+        SBE_HEADER_LEN + template.block_length as usize + msg.fields.len() * 16,
+    );
+
+    out.put_u16_le(template.block_length);
+    out.put_u16_le(template.template_id);
+    out.put_u16_le(template.schema_id);
+    out.put_u16_le(template.version);
+
+    let mut root_block = vec![0u8; template.block_length as usize];
+    for field in &template.scalars {
+        let offset = field.offset as usize;
+        if offset + field.ty.width() > root_block.len() {
+            // Misconfigured template: this field doesn't fit in
+            // `block_length`. Skip it rather than panicking on the slice
+            // index below.
+            continue;
+        }
+        match field.ty {
+            SbeScalarType::U8 => {
+                let byte = if field.tag == 35 {
+                    msg_type_byte(&msg.msg_type)
+                } else {
+                    msg.fields
+                        .get(&field.tag)
+                        .and_then(|v| v.parse::<u8>().ok())
+                        .unwrap_or(0)
+                };
+                root_block[offset] = byte;
+            }
+            SbeScalarType::U32Le => {
+                let value: u32 = msg
+                    .fields
+                    .get(&field.tag)
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(0);
+                root_block[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+            }
+            SbeScalarType::U64Le => {
+                let value: u64 = msg
+                    .fields
+                    .get(&field.tag)
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(0);
+                root_block[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+            }
+        }
+    }
+    out.put_slice(&root_block);
+
+    for tag in &template.variable_fields {
+        let value = msg.fields.get(tag).map(String::as_str).unwrap_or("");
+        out.put_u16_le(value.len() as u16);
+        out.put_slice(value.as_bytes());
+    }
+
+    out.freeze()
+}
+
+/// Decodes a buffer written by [`encode_sbe`] with the same `template`,
+/// reading the root block's scalars directly out of `buf` via
+/// `Buf::get_u32_le`/`get_u64_le` rather than UTF-8-parsing them.
+pub fn decode_sbe(buf: &[u8], template: &SbeTemplate) -> Result<FixMessage, String> {
+    if buf.len() < SBE_HEADER_LEN {
+        return Err("buffer shorter than the SBE header".to_string());
+    }
+    let mut header = &buf[..SBE_HEADER_LEN];
+    let block_length = header.get_u16_le();
+    let template_id = header.get_u16_le();
+    let schema_id = header.get_u16_le();
+    let version = header.get_u16_le();
+
+    if template_id != template.template_id {
+        return Err(format!(
+            "template_id mismatch: buffer has {}, expected {}",
+            template_id, template.template_id
+        ));
+    }
+    if schema_id != template.schema_id || version != template.version {
+        return Err("schema_id/version mismatch".to_string());
+    }
+
+    let root_start = SBE_HEADER_LEN;
+    let root_end = root_start + block_length as usize;
+    if buf.len() < root_end {
+        return Err("buffer shorter than its own block_length".to_string());
+    }
+    let root_block = &buf[root_start..root_end];
+
+    let mut msg_type = FixMsgType::Unknown(String::new());
+    let mut fields = std::collections::BTreeMap::new();
+
+    for field in &template.scalars {
+        let offset = field.offset as usize;
+        if offset + field.ty.width() > root_block.len() {
+            continue;
+        }
+        match field.ty {
+            SbeScalarType::U8 => {
+                let byte = root_block[offset];
+                if field.tag == 35 {
+                    msg_type = byte_to_msg_type(byte);
+                } else {
+                    fields.insert(field.tag, byte.to_string());
+                }
+            }
+            SbeScalarType::U32Le => {
+                let mut slice = &root_block[offset..offset + 4];
+                fields.insert(field.tag, slice.get_u32_le().to_string());
+            }
+            SbeScalarType::U64Le => {
+                let mut slice = &root_block[offset..offset + 8];
+                fields.insert(field.tag, slice.get_u64_le().to_string());
+            }
+        }
+    }
+
+    let mut rest = &buf[root_end..];
+    for tag in &template.variable_fields {
+        if rest.len() < 2 {
+            return Err(format!("truncated length prefix for tag {}", tag));
+        }
+        let len = rest.get_u16_le() as usize;
+        if rest.len() < len {
+            return Err(format!("truncated value for tag {}", tag));
+        }
+        let value = std::str::from_utf8(&rest[..len]).map_err(|_| "non-utf8 variable field")?;
+        fields.insert(*tag, value.to_string());
+        rest = &rest[len..];
+    }
+
+    Ok(FixMessage {
+        begin_string: "FIX.4.4".to_string(),
+        body_length: buf.len(),
+        msg_type,
+        fields,
+        groups: std::collections::BTreeMap::new(),
+    })
+}
@@ -1,7 +1,18 @@
 use crate::error::{FixgError, Result};
 use crate::messages::AdminMessage;
+use crate::transport::{CompressionKind, TransportConfig, TransportKind};
 use bytes::Bytes;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::sync::{mpsc, oneshot};
+use tokio::time::{Duration, Instant};
+
+/// Bound on a session's local outbound queue under `BackpressurePolicy::DropOldest`
+/// and `BackpressurePolicy::Conflate` (the latter only queues more than one
+/// item per key when sends arrive without a conflation key). Past this, the
+/// oldest queued item is dropped to make room rather than growing unbounded.
+const OUTBOUND_QUEUE_CAPACITY: usize = 256;
 
 /// Reasons why a FIX session might be disconnected.
 ///
@@ -15,8 +26,21 @@ pub enum DisconnectReason {
     ProtocolError,
     /// Connection timed out due to inactivity
     Timeout,
+    /// An automatic TestRequest(35=1), sent after the peer stayed silent for
+    /// longer than the heartbeat interval, went unanswered by a matching
+    /// Heartbeat(35=0) within the follow-up window. Unlike `Timeout` (plain
+    /// inactivity), this specifically means the liveness probe itself failed.
+    HeartbeatTimeout,
     /// The application requested disconnection
     ApplicationRequested,
+    /// The counterparty's Logon was rejected by the configured `AuthStrategy`
+    AuthenticationFailed,
+    /// Rejected by admission control: `max_sessions`/`max_sessions_per_comp_id`
+    /// was reached, or a session for this `SessionKey` is already active.
+    ConnectionLimit,
+    /// The gateway is shutting down: a Logout(35=5) was sent (or attempted)
+    /// and the session was closed as part of `GatewayHandle::shutdown`.
+    Shutdown,
     /// Disconnect reason is unknown or unspecified
     Unknown,
 }
@@ -31,6 +55,190 @@ pub enum OutboundPayload {
     Raw(Bytes),
     /// Structured administrative message (logon, heartbeat, etc.)
     Admin(AdminMessage),
+    /// Drain marker pushed by `Session::flush`. Carries no wire content;
+    /// once the connection's writer task dequeues it, every payload queued
+    /// ahead of it has already been written, so the sender is notified via
+    /// the enclosed oneshot.
+    Flush(oneshot::Sender<()>),
+}
+
+/// Startup readiness barrier backing `FixClientConfig::bootstrap_delay`.
+///
+/// Holds `Session::send`/`send_keyed` calls until either the configured
+/// delay elapses or every session the owning `FixClient` has configured
+/// (via `initiate`/`listen`) has reported `on_session_active`, whichever
+/// comes first. `Session::send_admin` is not gated, since admin traffic
+/// (including the initial Logon, which the gateway sends before a `Session`
+/// even exists) must proceed regardless.
+#[derive(Debug)]
+pub(crate) struct BootstrapGate {
+    ready: AtomicBool,
+    configured: AtomicUsize,
+    active: AtomicUsize,
+    notify: tokio::sync::Notify,
+    deadline: Instant,
+}
+
+impl BootstrapGate {
+    pub(crate) fn new(delay: Duration) -> Self {
+        Self {
+            ready: AtomicBool::new(false),
+            configured: AtomicUsize::new(0),
+            active: AtomicUsize::new(0),
+            notify: tokio::sync::Notify::new(),
+            deadline: Instant::now() + delay,
+        }
+    }
+
+    /// Call once per session this client expects to bring up, before it can
+    /// become active.
+    pub(crate) fn register_session(&self) {
+        self.configured.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Call once a session has reported `on_session_active`. Releases the
+    /// gate once every registered session has done so.
+    pub(crate) fn mark_active(&self) {
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        if active >= self.configured.load(Ordering::SeqCst) {
+            self.ready.store(true, Ordering::SeqCst);
+            self.notify.notify_waiters();
+        }
+    }
+
+    pub(crate) async fn wait(&self) {
+        // Register as a waiter *before* checking `ready`: `notify_waiters`
+        // doesn't store a permit for waiters that subscribe after it fires,
+        // so if `mark_active` ran between a `ready` check and this future
+        // actually registering, the wakeup would be lost and this call
+        // would block for the full delay instead of returning immediately.
+        // `notified()` alone doesn't register anything -- it only becomes a
+        // waiter once polled, so it must be pinned and `enable()`d here,
+        // before the `ready` check, to actually close that window.
+        let notified = self.notify.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+        if self.ready.load(Ordering::SeqCst) {
+            return;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep_until(self.deadline) => {}
+            _ = notified => {}
+        }
+        self.ready.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Acknowledgement returned for a `ClientCommand::Send`/`SendAdmin`, so
+/// producers can react to the session's outbound queue filling up instead of
+/// having the send silently dropped or the whole pipeline stall on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendAck {
+    /// Queued with plenty of headroom left.
+    Accepted,
+    /// Queued, but the session's outbound queue is at or above its
+    /// configured high-water mark; a `GatewayEvent::Backpressure` was also
+    /// emitted for this session.
+    Queued,
+    /// The session's outbound queue is completely full, or the session no
+    /// longer exists; the message was not queued.
+    Rejected,
+}
+
+/// How a [`Session`] handles outbound sends once its local outbound queue is
+/// saturated (a slow reader on the other end of the gateway's channel, e.g. a
+/// client that can't keep up with a 100Hz market-data feed).
+///
+/// Only [`Session::send_keyed`] is affected by `DropOldest`/`Conflate` — plain
+/// [`Session::send`] and [`Session::send_admin`] always block, since control
+/// traffic and unkeyed application messages must not be silently dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackpressurePolicy {
+    /// Await the writer; the caller's send is backpressured until there's
+    /// room. Matches the original behavior.
+    #[default]
+    Block,
+    /// Never block the caller. If the local queue is full, drop the oldest
+    /// queued item to make room for the new one.
+    DropOldest,
+    /// Never block the caller. Sends are keyed (e.g. by symbol); a new send
+    /// for a key already queued overwrites it in place instead of growing
+    /// the queue, so a lagging client only ever receives the latest value
+    /// per key. Keyless sends fall back to `DropOldest` behavior.
+    Conflate,
+}
+
+/// Per-session outbound backpressure counters, for spotting which clients are
+/// falling behind.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SessionMetrics {
+    /// Items dropped outright to make room (`DropOldest`, or `Conflate` on a
+    /// keyless send) since the session was created.
+    pub dropped: u64,
+    /// Items that overwrote an already-queued item for the same key
+    /// (`Conflate`) since the session was created.
+    pub conflated: u64,
+    /// Items currently sitting in this session's local outbound queue,
+    /// waiting to be forwarded to the gateway. Always 0 under
+    /// `BackpressurePolicy::Block`, which has no local queue.
+    pub queue_depth: usize,
+}
+
+#[derive(Debug, Default)]
+struct BackpressureCounters {
+    dropped: AtomicU64,
+    conflated: AtomicU64,
+}
+
+#[derive(Debug)]
+struct QueuedItem {
+    key: Option<String>,
+    payload: OutboundPayload,
+}
+
+/// Local outbound queue used by `BackpressurePolicy::DropOldest`/`Conflate`.
+/// Sits in front of the gateway's `send_tx` channel so a slow consumer can't
+/// stall the producer: pushes are synchronous and never block, while a
+/// background task drains the queue into `send_tx` (which may itself block,
+/// but only that task, not the caller).
+#[derive(Debug)]
+struct OutboundQueue {
+    policy: BackpressurePolicy,
+    items: Mutex<VecDeque<QueuedItem>>,
+    counters: BackpressureCounters,
+    notify: tokio::sync::Notify,
+}
+
+impl OutboundQueue {
+    fn push(&self, key: Option<String>, payload: OutboundPayload) {
+        let mut items = self.items.lock().expect("outbound queue mutex poisoned");
+
+        if self.policy == BackpressurePolicy::Conflate {
+            if let Some(key) = &key {
+                if let Some(existing) = items.iter_mut().find(|i| i.key.as_deref() == Some(key.as_str())) {
+                    existing.payload = payload;
+                    self.counters.conflated.fetch_add(1, Ordering::Relaxed);
+                    self.notify.notify_one();
+                    return;
+                }
+            }
+        }
+
+        if items.len() >= OUTBOUND_QUEUE_CAPACITY {
+            items.pop_front();
+            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        items.push_back(QueuedItem { key, payload });
+        self.notify.notify_one();
+    }
+
+    fn metrics(&self) -> SessionMetrics {
+        SessionMetrics {
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+            conflated: self.counters.conflated.load(Ordering::Relaxed),
+            queue_depth: self.items.lock().expect("outbound queue mutex poisoned").len(),
+        }
+    }
 }
 
 /// Represents an active FIX session.
@@ -44,6 +252,16 @@ pub struct Session {
     id: u64,
     /// Channel for sending outbound messages
     send_tx: mpsc::Sender<OutboundPayload>,
+    /// Which concrete transport (TCP/TLS/WebSocket) this session negotiated.
+    transport: TransportKind,
+    /// Local outbound queue backing `BackpressurePolicy::DropOldest`/`Conflate`.
+    /// `None` under `BackpressurePolicy::Block`, which sends straight to
+    /// `send_tx` and needs no local queue.
+    outbound_queue: Option<Arc<OutboundQueue>>,
+    /// Set when the owning `FixClient` was configured with
+    /// `FixClientConfig::bootstrap_delay`; gates `send`/`send_keyed` until
+    /// the barrier releases.
+    bootstrap_gate: Option<Arc<BootstrapGate>>,
 }
 
 impl Session {
@@ -51,7 +269,32 @@ impl Session {
         self.id
     }
 
+    /// Attaches the `FixClient`'s startup barrier to this session, gating
+    /// `send`/`send_keyed` until it releases. Internal wiring for
+    /// `FixClientConfig::bootstrap_delay`.
+    pub(crate) fn with_bootstrap_gate(mut self, gate: Arc<BootstrapGate>) -> Self {
+        self.bootstrap_gate = Some(gate);
+        self
+    }
+
+    /// Which concrete transport (TCP/TLS/WebSocket) this session negotiated.
+    pub fn transport(&self) -> TransportKind {
+        self.transport
+    }
+
+    /// Snapshot of this session's outbound backpressure counters. Always
+    /// zero under `BackpressurePolicy::Block`.
+    pub fn metrics(&self) -> SessionMetrics {
+        self.outbound_queue
+            .as_ref()
+            .map(|q| q.metrics())
+            .unwrap_or_default()
+    }
+
     pub async fn send(&self, payload: Bytes) -> Result<()> {
+        if let Some(gate) = &self.bootstrap_gate {
+            gate.wait().await;
+        }
         self.send_tx
             .send(OutboundPayload::Raw(payload))
             .await
@@ -59,6 +302,25 @@ impl Session {
             .map(|_| ())
     }
 
+    /// Sends `payload`, applying this session's configured
+    /// `BackpressurePolicy` keyed by `key` (e.g. a market-data symbol). Under
+    /// `Conflate`, a send for a key that's still queued overwrites it rather
+    /// than growing the queue, so a lagging client receives the latest value
+    /// per key instead of a stale backlog. Apart from waiting on the
+    /// bootstrap gate, never blocks except under `BackpressurePolicy::Block`.
+    pub async fn send_keyed(&self, key: impl Into<String>, payload: Bytes) -> Result<()> {
+        if let Some(gate) = &self.bootstrap_gate {
+            gate.wait().await;
+        }
+        match &self.outbound_queue {
+            Some(queue) => {
+                queue.push(Some(key.into()), OutboundPayload::Raw(payload));
+                Ok(())
+            }
+            None => self.send(payload).await,
+        }
+    }
+
     pub async fn send_admin(&self, msg: AdminMessage) -> Result<()> {
         self.send_tx
             .send(OutboundPayload::Admin(msg))
@@ -66,6 +328,53 @@ impl Session {
             .map_err(|_| FixgError::ChannelClosed)
             .map(|_| ())
     }
+
+    /// Resolves once every payload queued ahead of this call has been
+    /// written to the transport — a deterministic replacement for a fixed
+    /// `sleep` used to "let sends settle" before asserting on state or
+    /// shutting down. Bypasses `bootstrap_gate`: flushing is a drain
+    /// operation, not an application send.
+    pub async fn flush(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        match &self.outbound_queue {
+            Some(queue) => queue.push(None, OutboundPayload::Flush(tx)),
+            None => self
+                .send_tx
+                .send(OutboundPayload::Flush(tx))
+                .await
+                .map_err(|_| FixgError::ChannelClosed)?,
+        }
+        rx.await.map_err(|_| FixgError::ChannelClosed)
+    }
+}
+
+/// Configuration for automatic initiator reconnection with exponential backoff.
+///
+/// When set on a [`SessionConfig`], a disconnect with [`DisconnectReason::PeerClosed`],
+/// [`DisconnectReason::Timeout`], or [`DisconnectReason::Unknown`] triggers a
+/// reconnect loop that re-dials and re-drives the logon handshake, resuming
+/// the persisted outbound/inbound FIX sequence numbers from `storage`.
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// Delay before the first reconnect attempt.
+    pub initial_interval: Duration,
+    /// Upper bound the backoff delay is capped at.
+    pub max_interval: Duration,
+    /// Multiplier applied to the delay after each failed attempt.
+    pub multiplier: f64,
+    /// Maximum number of reconnect attempts before giving up, or `None` for unlimited.
+    pub max_retries: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(30),
+            multiplier: 2.0,
+            max_retries: None,
+        }
+    }
 }
 
 /// Configuration for establishing a FIX session.
@@ -84,6 +393,38 @@ pub struct SessionConfig {
     pub target_comp_id: String,
     /// Heartbeat interval in seconds
     pub heartbeat_interval_secs: u32,
+    /// Automatic reconnection policy; `None` disables reconnection entirely
+    /// (a lost connection simply ends the session, the prior behavior).
+    pub reconnect: Option<ReconnectConfig>,
+    /// Send ResetSeqNumFlag(141=Y) on the initial Logon and restart both
+    /// sequence counters at 1, discarding any persisted journal for this
+    /// session. Also honored if the counterparty's Logon carries it.
+    pub reset_seq_num: bool,
+    /// Overrides the gateway's configured `TransportConfig` for this session
+    /// only. `None` uses the gateway-wide transport (the prior behavior).
+    pub transport: Option<TransportConfig>,
+    /// How `Session::send_keyed` behaves once this session's local outbound
+    /// queue is saturated. Defaults to `BackpressurePolicy::Block`, the
+    /// prior behavior.
+    pub backpressure_policy: BackpressurePolicy,
+    /// BeginString (tag 8) sent on every message this session emits.
+    /// Defaults to `"FIX.4.4"`. Set to `"FIXT.1.1"` to speak the FIX
+    /// transport-version split, pairing with `default_appl_ver_id` to pin
+    /// the application version carried in Logon's DefaultApplVerID(1137).
+    pub begin_string: String,
+    /// Expected DefaultApplVerID(1137) for a FIXT.1.1 session. `None` skips
+    /// application-version checking entirely (the prior behavior, and the
+    /// only sensible default for a plain FIX.4.4 `begin_string`). When set,
+    /// a peer Logon advertising a different ApplVerID is rejected with a
+    /// Logout rather than accepted onto a session expecting another
+    /// application message layout.
+    pub default_appl_ver_id: Option<String>,
+    /// Compression codecs this session offers during the post-connect
+    /// capability handshake, in preference order. Empty (the default) offers
+    /// none, so the session stays plaintext FIX over whatever `transport`
+    /// carries it -- the prior behavior. See
+    /// [`crate::transport::negotiate_compression`].
+    pub compression: Vec<CompressionKind>,
 }
 
 impl SessionConfig {
@@ -108,6 +449,20 @@ pub struct SessionConfigBuilder {
     target_comp_id: Option<String>,
     /// Heartbeat interval in seconds
     heartbeat_interval_secs: Option<u32>,
+    /// Automatic reconnection policy
+    reconnect: Option<ReconnectConfig>,
+    /// Whether to send ResetSeqNumFlag(141=Y) on the initial Logon
+    reset_seq_num: bool,
+    /// Per-session transport override
+    transport: Option<TransportConfig>,
+    /// Outbound backpressure policy for `Session::send_keyed`
+    backpressure_policy: BackpressurePolicy,
+    /// BeginString (tag 8) this session emits
+    begin_string: Option<String>,
+    /// Expected DefaultApplVerID(1137) for a FIXT.1.1 session
+    default_appl_ver_id: Option<String>,
+    /// Compression codecs this session offers, in preference order
+    compression: Vec<CompressionKind>,
 }
 
 impl SessionConfigBuilder {
@@ -131,6 +486,43 @@ impl SessionConfigBuilder {
         self.heartbeat_interval_secs = Some(v);
         self
     }
+    pub fn reconnect(mut self, cfg: ReconnectConfig) -> Self {
+        self.reconnect = Some(cfg);
+        self
+    }
+    pub fn reset_seq_num(mut self, v: bool) -> Self {
+        self.reset_seq_num = v;
+        self
+    }
+    /// Overrides the gateway's configured transport for this session only.
+    pub fn transport(mut self, cfg: TransportConfig) -> Self {
+        self.transport = Some(cfg);
+        self
+    }
+    /// Sets how `Session::send_keyed` behaves once the local outbound queue
+    /// is saturated.
+    pub fn backpressure_policy(mut self, v: BackpressurePolicy) -> Self {
+        self.backpressure_policy = v;
+        self
+    }
+    /// Sets BeginString(8). Defaults to `"FIX.4.4"`. Use `"FIXT.1.1"` when
+    /// pairing with `default_appl_ver_id` to pin an application version.
+    pub fn begin_string(mut self, v: impl Into<String>) -> Self {
+        self.begin_string = Some(v.into());
+        self
+    }
+    /// Sets the expected DefaultApplVerID(1137) for this session's Logon.
+    /// A peer Logon advertising a different value is rejected with a Logout.
+    pub fn default_appl_ver_id(mut self, v: impl Into<String>) -> Self {
+        self.default_appl_ver_id = Some(v.into());
+        self
+    }
+    /// Offers `codecs` (in preference order) during the post-connect
+    /// compression capability handshake. Unset (the default) offers none.
+    pub fn compression(mut self, codecs: impl Into<Vec<CompressionKind>>) -> Self {
+        self.compression = codecs.into();
+        self
+    }
 
     pub fn build(self) -> Result<SessionConfig> {
         Ok(SessionConfig {
@@ -147,17 +539,74 @@ impl SessionConfigBuilder {
                 .target_comp_id
                 .ok_or_else(|| FixgError::InvalidConfig("target_comp_id missing".into()))?,
             heartbeat_interval_secs: self.heartbeat_interval_secs.unwrap_or(30),
+            reconnect: self.reconnect,
+            reset_seq_num: self.reset_seq_num,
+            transport: self.transport,
+            backpressure_policy: self.backpressure_policy,
+            begin_string: self.begin_string.unwrap_or_else(|| "FIX.4.4".to_string()),
+            default_appl_ver_id: self.default_appl_ver_id,
+            compression: self.compression,
         })
     }
 }
 
 // Internal helper to create a Session with a send channel
-pub(crate) fn new_session(session_id: u64) -> (Session, mpsc::Receiver<OutboundPayload>) {
+pub(crate) fn new_session(
+    session_id: u64,
+    transport: TransportKind,
+) -> (Session, mpsc::Receiver<OutboundPayload>) {
+    new_session_with_backpressure_policy(session_id, transport, BackpressurePolicy::Block)
+}
+
+/// Like [`new_session`], but wires up a local outbound queue and background
+/// forwarding task when `policy` isn't `BackpressurePolicy::Block`.
+pub(crate) fn new_session_with_backpressure_policy(
+    session_id: u64,
+    transport: TransportKind,
+    policy: BackpressurePolicy,
+) -> (Session, mpsc::Receiver<OutboundPayload>) {
     let (tx, rx) = mpsc::channel::<OutboundPayload>(1024);
+
+    let outbound_queue = if policy == BackpressurePolicy::Block {
+        None
+    } else {
+        let queue = Arc::new(OutboundQueue {
+            policy,
+            items: Mutex::new(VecDeque::new()),
+            counters: BackpressureCounters::default(),
+            notify: tokio::sync::Notify::new(),
+        });
+
+        let forwarding_queue = queue.clone();
+        let forward_tx = tx.clone();
+        tokio::spawn(async move {
+            loop {
+                let next = forwarding_queue
+                    .items
+                    .lock()
+                    .expect("outbound queue mutex poisoned")
+                    .pop_front();
+                match next {
+                    Some(item) => {
+                        if forward_tx.send(item.payload).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => forwarding_queue.notify.notified().await,
+                }
+            }
+        });
+
+        Some(queue)
+    };
+
     (
         Session {
             id: session_id,
             send_tx: tx,
+            transport,
+            outbound_queue,
+            bootstrap_gate: None,
         },
         rx,
     )
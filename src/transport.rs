@@ -0,0 +1,675 @@
+use crate::error::{FixgError, Result};
+use async_compression::tokio::{bufread::ZstdDecoder, write::ZstdEncoder};
+use async_trait::async_trait;
+use bytes::BytesMut;
+use futures_util::{Sink, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Mutex as AsyncMutex};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+/// Boxed, type-erased halves of a split transport connection so the session
+/// plumbing (heartbeat timers, framing, resend/gap-fill) can run unchanged
+/// regardless of which concrete transport is in use.
+pub type TransportRead = Box<dyn AsyncRead + Send + Unpin>;
+pub type TransportWrite = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Transport-level configuration for a gateway's acceptor and initiated sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TransportConfig {
+    /// Plain TCP, no encryption.
+    Tcp,
+    /// TLS over TCP via tokio-rustls.
+    Tls {
+        /// PEM-encoded certificate chain presented by the acceptor.
+        cert_path: PathBuf,
+        /// PEM-encoded private key matching `cert_path`.
+        key_path: PathBuf,
+        /// PEM-encoded CA bundle initiator sessions use to verify the peer.
+        /// Falls back to the platform's webpki roots when absent.
+        ca_path: Option<PathBuf>,
+        /// PEM-encoded CA bundle the acceptor uses to verify a connecting
+        /// client's certificate, for mutual TLS. Required when
+        /// `require_client_auth` is set; ignored for initiator sessions.
+        client_ca: Option<PathBuf>,
+        /// Whether the acceptor rejects a TLS handshake that doesn't
+        /// present a certificate trusted by `client_ca`. Exchanges that
+        /// don't require client certificates (most do not) leave this `false`.
+        require_client_auth: bool,
+    },
+    /// FIX-over-WebSocket, as used by a growing number of crypto venues. The
+    /// session framing/parsing layer is unchanged; each WebSocket Binary (or
+    /// Text) frame is treated as a chunk of the same FIX byte stream a TCP
+    /// transport would have produced.
+    WebSocket {
+        /// `ws://` or `wss://` URL initiator sessions connect to. Unused for
+        /// acceptor sessions, which upgrade the inbound TCP connection.
+        url: String,
+        /// Optional `Sec-WebSocket-Protocol` value to negotiate.
+        subprotocol: Option<String>,
+    },
+    /// In-process duplex channel, no socket involved. `name` pairs an
+    /// acceptor's `bind` with an initiator's `connect` within the same
+    /// process, so tests can drive a full FIX session (real framing, codec,
+    /// sequence numbers) deterministically and without a port.
+    Memory {
+        /// Identifies which acceptor a `connect` call pairs up with.
+        name: String,
+    },
+}
+
+impl Default for TransportConfig {
+    fn default() -> Self {
+        TransportConfig::Tcp
+    }
+}
+
+/// Identifies which concrete transport carried a session's byte stream,
+/// recorded on [`crate::session::Session`] and reported via
+/// `GatewayEvent::SessionActive` so clients can observe what a session
+/// actually negotiated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransportKind {
+    #[default]
+    Tcp,
+    Tls,
+    WebSocket,
+    Memory,
+}
+
+impl TransportConfig {
+    pub fn kind(&self) -> TransportKind {
+        match self {
+            TransportConfig::Tcp => TransportKind::Tcp,
+            TransportConfig::Tls { .. } => TransportKind::Tls,
+            TransportConfig::WebSocket { .. } => TransportKind::WebSocket,
+            TransportConfig::Memory { .. } => TransportKind::Memory,
+        }
+    }
+}
+
+/// Compression codecs a session can advertise during the post-connect
+/// capability handshake ([`negotiate_compression`]). Orthogonal to
+/// [`TransportConfig`]: compression is layered on top of whichever transport
+/// (TCP, TLS, WebSocket, Memory) carried the connection, so it's configured
+/// separately via `SessionConfig::compression` rather than as another
+/// `TransportConfig` variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressionKind {
+    /// Zstandard, via the streaming `async-compression` codec.
+    Zstd,
+}
+
+impl CompressionKind {
+    fn tag(self) -> u8 {
+        match self {
+            CompressionKind::Zstd => 1,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(CompressionKind::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// Performs the in-band compression capability exchange: called immediately
+/// after `Transport::connect`/`accept` returns and before the first FIX
+/// message (the Logon) is sent. Both sides write a one-byte count followed by
+/// one tag byte per entry of `our_supported` (in preference order), then read
+/// the peer's equivalent frame. Each side takes the first entry of its own
+/// `our_supported` that also appears in the peer's list; neither side's
+/// order overrides the other's, so two peers sharing more than one codec in
+/// different orders can settle on different codecs (with `Zstd` the only
+/// `CompressionKind`, both sides always agree). Returns `None` (no compression) if
+/// `our_supported` is empty or the two lists share nothing, in which case
+/// `read`/`write` are handed back unwrapped.
+///
+/// Once a codec is negotiated, the returned `TransportRead`/`TransportWrite`
+/// transparently (de)compress every byte flowing through them -- the framing
+/// layer above (`FixCodec`, `Session::send_raw`, `InboundMessage::body`)
+/// keeps reading/writing plain FIX bytes and never knows compression is
+/// involved, the same way `WsRead`/`WsWrite` hide WebSocket framing from it.
+pub async fn negotiate_compression(
+    mut read: TransportRead,
+    mut write: TransportWrite,
+    our_supported: &[CompressionKind],
+) -> Result<(TransportRead, TransportWrite, Option<CompressionKind>)> {
+    let our_frame: Vec<u8> = std::iter::once(our_supported.len() as u8)
+        .chain(our_supported.iter().map(|c| c.tag()))
+        .collect();
+
+    let write_fut = write.write_all(&our_frame);
+    let read_fut = async {
+        let mut count_buf = [0u8; 1];
+        read.read_exact(&mut count_buf).await?;
+        let mut tags = vec![0u8; count_buf[0] as usize];
+        read.read_exact(&mut tags).await?;
+        Ok::<_, std::io::Error>(tags)
+    };
+    let (write_res, read_res) = tokio::join!(write_fut, read_fut);
+    write_res?;
+    let peer_tags = read_res?;
+    let peer_supported: Vec<CompressionKind> =
+        peer_tags.into_iter().filter_map(CompressionKind::from_tag).collect();
+
+    let negotiated = our_supported.iter().copied().find(|c| peer_supported.contains(c));
+
+    match negotiated {
+        Some(codec) => {
+            let (read, write) = wrap_compressed(read, write, codec);
+            Ok((read, write, Some(codec)))
+        }
+        None => Ok((read, write, None)),
+    }
+}
+
+/// Wraps `poll_write`s split-out streaming compressor so every logical
+/// `write_all` reaches the peer promptly. `ZstdEncoder` buffers output until
+/// flushed, but FIX relies on each message arriving without delay
+/// (heartbeats, TestRequest round trips) -- without this, a compressed
+/// session would silently stall until the encoder's internal buffer happened
+/// to fill.
+struct FlushingWrite<W> {
+    inner: W,
+    // Set when a previous `poll_write`'s eager flush returned `Pending`.
+    // Nothing else re-polls it (no caller in this crate calls `flush()`
+    // explicitly), so it must be driven to completion here before any new
+    // bytes are accepted -- otherwise it's abandoned and the message it
+    // covers can sit unflushed indefinitely.
+    flush_pending: bool,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for FlushingWrite<W> {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        if self.flush_pending {
+            match Pin::new(&mut self.inner).poll_flush(cx) {
+                Poll::Ready(Ok(())) => self.flush_pending = false,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let n = match Pin::new(&mut self.inner).poll_write(cx, buf) {
+            Poll::Ready(Ok(n)) => n,
+            other => return other,
+        };
+        match Pin::new(&mut self.inner).poll_flush(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(n)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            // The write itself landed; mark the flush outstanding so it's
+            // finished off by the next `poll_write`/`poll_flush` instead of
+            // being dropped on the floor.
+            Poll::Pending => {
+                self.flush_pending = true;
+                Poll::Ready(Ok(n))
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match Pin::new(&mut self.inner).poll_flush(cx) {
+            Poll::Ready(Ok(())) => {
+                self.flush_pending = false;
+                Poll::Ready(Ok(()))
+            }
+            other => other,
+        }
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+/// Wraps `read`/`write` so every byte is transparently (de)compressed with
+/// `codec`.
+fn wrap_compressed(read: TransportRead, write: TransportWrite, codec: CompressionKind) -> (TransportRead, TransportWrite) {
+    match codec {
+        CompressionKind::Zstd => {
+            let read: TransportRead = Box::new(ZstdDecoder::new(BufReader::new(read)));
+            let write: TransportWrite = Box::new(FlushingWrite { inner: ZstdEncoder::new(write), flush_pending: false });
+            (read, write)
+        }
+    }
+}
+
+/// Listener handle a `Transport` binds and later `accept`s connections from.
+/// TCP/TLS/WebSocket all bind a real socket; `MemoryTransport` instead hands
+/// back the receiving end of a registry entry other processes-local
+/// `connect` calls feed into.
+pub enum GatewayListener {
+    Tcp(TcpListener),
+    Memory(AsyncMutex<mpsc::Receiver<(TransportRead, TransportWrite)>>),
+}
+
+/// Abstracts session transport setup so the gateway can accept/connect over
+/// TCP, TLS, WebSocket, or an in-memory duplex channel without the session
+/// loop caring which one is in play.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Binds `addr`, returning a listener `accept` can later pull connections from.
+    async fn bind(&self, addr: SocketAddr) -> Result<GatewayListener>;
+    /// Accepts one inbound connection on `listener`, returning split halves.
+    async fn accept(&self, listener: &GatewayListener) -> Result<(TransportRead, TransportWrite)>;
+    /// Connects to `addr` (host:port), returning split halves. `server_name`
+    /// is the hostname TLS transports verify the peer certificate against.
+    async fn connect(&self, addr: &str, server_name: &str) -> Result<(TransportRead, TransportWrite)>;
+    /// Identifies which concrete transport this is, for session/event reporting.
+    fn kind(&self) -> TransportKind;
+}
+
+/// Binds a real `TcpListener`, for the TCP/TLS/WebSocket transports, which
+/// all accept a plain TCP connection and optionally upgrade it afterward.
+async fn bind_tcp(addr: SocketAddr) -> Result<GatewayListener> {
+    Ok(GatewayListener::Tcp(TcpListener::bind(addr).await?))
+}
+
+/// Unwraps a `GatewayListener::Tcp`, for transports that only ever bind real sockets.
+fn expect_tcp_listener<'a>(listener: &'a GatewayListener, who: &str) -> Result<&'a TcpListener> {
+    match listener {
+        GatewayListener::Tcp(listener) => Ok(listener),
+        GatewayListener::Memory(_) => Err(FixgError::InvalidConfig(format!(
+            "{who} requires a TCP listener, got a Memory listener"
+        ))),
+    }
+}
+
+/// Plain TCP transport.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpTransport;
+
+#[async_trait]
+impl Transport for TcpTransport {
+    async fn bind(&self, addr: SocketAddr) -> Result<GatewayListener> {
+        bind_tcp(addr).await
+    }
+
+    async fn accept(&self, listener: &GatewayListener) -> Result<(TransportRead, TransportWrite)> {
+        let listener = expect_tcp_listener(listener, "TcpTransport")?;
+        let (stream, _addr) = listener.accept().await?;
+        let (r, w) = tokio::io::split(stream);
+        Ok((Box::new(r), Box::new(w)))
+    }
+
+    async fn connect(&self, addr: &str, _server_name: &str) -> Result<(TransportRead, TransportWrite)> {
+        let stream = TcpStream::connect(addr).await?;
+        let (r, w) = tokio::io::split(stream);
+        Ok((Box::new(r), Box::new(w)))
+    }
+
+    fn kind(&self) -> TransportKind {
+        TransportKind::Tcp
+    }
+}
+
+/// TLS transport backed by tokio-rustls; presents a server certificate for
+/// acceptor sessions and verifies the peer certificate for initiator sessions.
+pub struct TlsTransport {
+    acceptor: tokio_rustls::TlsAcceptor,
+    connector: tokio_rustls::TlsConnector,
+}
+
+impl TlsTransport {
+    pub fn from_config(cfg: &TransportConfig) -> Result<Self> {
+        let TransportConfig::Tls {
+            cert_path,
+            key_path,
+            ca_path,
+            client_ca,
+            require_client_auth,
+        } = cfg
+        else {
+            return Err(FixgError::InvalidConfig(
+                "TlsTransport requires TransportConfig::Tls".into(),
+            ));
+        };
+
+        let certs = load_certs(cert_path)?;
+        let key = load_private_key(key_path)?;
+        let server_config_builder = if *require_client_auth {
+            let client_ca = client_ca.as_ref().ok_or_else(|| {
+                FixgError::InvalidConfig(
+                    "require_client_auth is set but no client_ca was provided".into(),
+                )
+            })?;
+            let mut client_roots = rustls::RootCertStore::empty();
+            for cert in load_certs(client_ca)? {
+                client_roots
+                    .add(cert)
+                    .map_err(|e| FixgError::InvalidConfig(format!("invalid client CA cert: {e}")))?;
+            }
+            let verifier = rustls::server::WebPkiClientVerifier::builder(Arc::new(client_roots))
+                .build()
+                .map_err(|e| FixgError::InvalidConfig(format!("invalid client CA bundle: {e}")))?;
+            rustls::ServerConfig::builder().with_client_cert_verifier(verifier)
+        } else {
+            rustls::ServerConfig::builder().with_no_client_auth()
+        };
+        let server_config = server_config_builder
+            .with_single_cert(certs, key)
+            .map_err(|e| FixgError::InvalidConfig(format!("invalid TLS server cert/key: {e}")))?;
+        let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(server_config));
+
+        let mut roots = rustls::RootCertStore::empty();
+        if let Some(ca_path) = ca_path {
+            for cert in load_certs(ca_path)? {
+                roots
+                    .add(cert)
+                    .map_err(|e| FixgError::InvalidConfig(format!("invalid CA cert: {e}")))?;
+            }
+        } else {
+            roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        }
+        let client_config = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let connector = tokio_rustls::TlsConnector::from(Arc::new(client_config));
+
+        Ok(Self { acceptor, connector })
+    }
+}
+
+#[async_trait]
+impl Transport for TlsTransport {
+    async fn bind(&self, addr: SocketAddr) -> Result<GatewayListener> {
+        bind_tcp(addr).await
+    }
+
+    async fn accept(&self, listener: &GatewayListener) -> Result<(TransportRead, TransportWrite)> {
+        let listener = expect_tcp_listener(listener, "TlsTransport")?;
+        let (stream, _addr) = listener.accept().await?;
+        let tls_stream = self
+            .acceptor
+            .accept(stream)
+            .await
+            .map_err(|e| FixgError::Protocol(format!("TLS handshake failed: {e}")))?;
+        let (r, w) = tokio::io::split(tls_stream);
+        Ok((Box::new(r), Box::new(w)))
+    }
+
+    async fn connect(&self, addr: &str, server_name: &str) -> Result<(TransportRead, TransportWrite)> {
+        let stream = TcpStream::connect(addr).await?;
+        let name = rustls::pki_types::ServerName::try_from(server_name.to_string())
+            .map_err(|e| FixgError::InvalidConfig(format!("invalid TLS server name {server_name:?}: {e}")))?;
+        let tls_stream = self
+            .connector
+            .connect(name, stream)
+            .await
+            .map_err(|e| FixgError::Protocol(format!("TLS handshake failed: {e}")))?;
+        let (r, w) = tokio::io::split(tls_stream);
+        Ok((Box::new(r), Box::new(w)))
+    }
+
+    fn kind(&self) -> TransportKind {
+        TransportKind::Tls
+    }
+}
+
+/// Adapts a WebSocket's message-oriented [`futures_util::Stream`] of
+/// [`Message`]s into a plain byte stream: Binary/Text frames are buffered and
+/// handed out via `AsyncRead`, other frame types (ping/pong/close) are
+/// skipped over transparently.
+struct WsRead<S> {
+    inner: futures_util::stream::SplitStream<WebSocketStream<S>>,
+    pending: BytesMut,
+}
+
+impl<S> WsRead<S> {
+    fn new(inner: futures_util::stream::SplitStream<WebSocketStream<S>>) -> Self {
+        Self { inner, pending: BytesMut::new() }
+    }
+}
+
+impl<S> AsyncRead for WsRead<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        loop {
+            if !self.pending.is_empty() {
+                let n = buf.remaining().min(self.pending.len());
+                let chunk = self.pending.split_to(n);
+                buf.put_slice(&chunk);
+                return Poll::Ready(Ok(()));
+            }
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(Message::Binary(data)))) => {
+                    self.pending.extend_from_slice(&data);
+                }
+                Poll::Ready(Some(Ok(Message::Text(text)))) => {
+                    self.pending.extend_from_slice(text.as_bytes());
+                }
+                Poll::Ready(Some(Ok(_))) => continue, // ping/pong/frame/close
+                Poll::Ready(Some(Err(e))) => {
+                    return Poll::Ready(Err(std::io::Error::new(std::io::ErrorKind::Other, e)));
+                }
+                Poll::Ready(None) => return Poll::Ready(Ok(())), // EOF
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// The write half of the same bridge: every `AsyncWrite` write is forwarded
+/// as one WebSocket Binary frame.
+struct WsWrite<S> {
+    inner: futures_util::stream::SplitSink<WebSocketStream<S>, Message>,
+}
+
+impl<S> WsWrite<S> {
+    fn new(inner: futures_util::stream::SplitSink<WebSocketStream<S>, Message>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S> AsyncWrite for WsWrite<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        match Pin::new(&mut self.inner).poll_ready(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(std::io::Error::new(std::io::ErrorKind::Other, e))),
+            Poll::Pending => return Poll::Pending,
+        }
+        match Pin::new(&mut self.inner).start_send(Message::Binary(buf.to_vec())) {
+            Ok(()) => Poll::Ready(Ok(buf.len())),
+            Err(e) => Poll::Ready(Err(std::io::Error::new(std::io::ErrorKind::Other, e))),
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner)
+            .poll_flush(cx)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner)
+            .poll_close(cx)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+    }
+}
+
+/// FIX-over-WebSocket transport. The session framing/parsing layer is
+/// unchanged; [`WsRead`]/[`WsWrite`] bridge the WebSocket's Binary/Text
+/// frames into the same `AsyncRead`/`AsyncWrite` halves TCP and TLS produce.
+pub struct WebSocketTransport {
+    url: String,
+    subprotocol: Option<String>,
+}
+
+impl WebSocketTransport {
+    pub fn from_config(cfg: &TransportConfig) -> Result<Self> {
+        let TransportConfig::WebSocket { url, subprotocol } = cfg else {
+            return Err(FixgError::InvalidConfig(
+                "WebSocketTransport requires TransportConfig::WebSocket".into(),
+            ));
+        };
+        Ok(Self { url: url.clone(), subprotocol: subprotocol.clone() })
+    }
+}
+
+#[async_trait]
+impl Transport for WebSocketTransport {
+    async fn bind(&self, addr: SocketAddr) -> Result<GatewayListener> {
+        bind_tcp(addr).await
+    }
+
+    async fn accept(&self, listener: &GatewayListener) -> Result<(TransportRead, TransportWrite)> {
+        let listener = expect_tcp_listener(listener, "WebSocketTransport")?;
+        let (stream, _addr) = listener.accept().await?;
+        let ws_stream = tokio_tungstenite::accept_async(stream)
+            .await
+            .map_err(|e| FixgError::Protocol(format!("WebSocket handshake failed: {e}")))?;
+        let (sink, stream) = ws_stream.split();
+        Ok((Box::new(WsRead::new(stream)), Box::new(WsWrite::new(sink))))
+    }
+
+    async fn connect(&self, _addr: &str, _server_name: &str) -> Result<(TransportRead, TransportWrite)> {
+        let mut request = self
+            .url
+            .clone()
+            .into_client_request()
+            .map_err(|e| FixgError::InvalidConfig(format!("invalid WebSocket URL {:?}: {e}", self.url)))?;
+        if let Some(subprotocol) = &self.subprotocol {
+            request.headers_mut().insert(
+                "Sec-WebSocket-Protocol",
+                subprotocol
+                    .parse()
+                    .map_err(|e| FixgError::InvalidConfig(format!("invalid subprotocol {subprotocol:?}: {e}")))?,
+            );
+        }
+        let (ws_stream, _response) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| FixgError::Protocol(format!("WebSocket handshake failed: {e}")))?;
+        let (sink, stream) = ws_stream.split();
+        Ok((Box::new(WsRead::new(stream)), Box::new(WsWrite::new(sink))))
+    }
+
+    fn kind(&self) -> TransportKind {
+        TransportKind::WebSocket
+    }
+}
+
+/// Pending in-memory acceptors, keyed by `TransportConfig::Memory`'s `name`.
+/// `bind` registers a sender here; `connect` looks it up and hands the
+/// acceptor-side half of a fresh `tokio::io::duplex` through it.
+type MemoryHalves = (TransportRead, TransportWrite);
+static MEMORY_REGISTRY: OnceLock<AsyncMutex<HashMap<String, mpsc::Sender<MemoryHalves>>>> = OnceLock::new();
+
+fn memory_registry() -> &'static AsyncMutex<HashMap<String, mpsc::Sender<MemoryHalves>>> {
+    MEMORY_REGISTRY.get_or_init(|| AsyncMutex::new(HashMap::new()))
+}
+
+/// In-memory transport: `connect` and `accept` never touch a socket.
+/// Sessions are paired up through a process-local registry keyed by `name`,
+/// so two `FixClient`s (or a client and an acceptor) in the same process can
+/// drive a full FIX session — real framing, codec, and sequence numbers
+/// included — with nothing on the wire.
+#[derive(Debug, Clone)]
+pub struct MemoryTransport {
+    name: String,
+}
+
+impl MemoryTransport {
+    pub fn from_config(cfg: &TransportConfig) -> Result<Self> {
+        let TransportConfig::Memory { name } = cfg else {
+            return Err(FixgError::InvalidConfig(
+                "MemoryTransport requires TransportConfig::Memory".into(),
+            ));
+        };
+        Ok(Self { name: name.clone() })
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn bind(&self, _addr: SocketAddr) -> Result<GatewayListener> {
+        let (tx, rx) = mpsc::channel::<MemoryHalves>(16);
+        memory_registry().lock().await.insert(self.name.clone(), tx);
+        Ok(GatewayListener::Memory(AsyncMutex::new(rx)))
+    }
+
+    async fn accept(&self, listener: &GatewayListener) -> Result<(TransportRead, TransportWrite)> {
+        match listener {
+            GatewayListener::Memory(rx) => rx
+                .lock()
+                .await
+                .recv()
+                .await
+                .ok_or_else(|| FixgError::Protocol("memory transport listener closed".into())),
+            GatewayListener::Tcp(_) => Err(FixgError::InvalidConfig(
+                "MemoryTransport requires a Memory listener".into(),
+            )),
+        }
+    }
+
+    async fn connect(&self, _addr: &str, _server_name: &str) -> Result<(TransportRead, TransportWrite)> {
+        let tx = memory_registry()
+            .lock()
+            .await
+            .get(&self.name)
+            .cloned()
+            .ok_or_else(|| {
+                FixgError::InvalidConfig(format!("no in-memory listener registered as {:?}", self.name))
+            })?;
+
+        let (acceptor_side, initiator_side) = tokio::io::duplex(8192);
+        let (acceptor_read, acceptor_write) = tokio::io::split(acceptor_side);
+        let (initiator_read, initiator_write) = tokio::io::split(initiator_side);
+
+        tx.send((Box::new(acceptor_read), Box::new(acceptor_write)))
+            .await
+            .map_err(|_| FixgError::Protocol("memory transport acceptor gone".into()))?;
+
+        Ok((Box::new(initiator_read), Box::new(initiator_write)))
+    }
+
+    fn kind(&self) -> TransportKind {
+        TransportKind::Memory
+    }
+}
+
+fn load_certs(path: &PathBuf) -> Result<Vec<rustls::pki_types::CertificateDer<'static>>> {
+    let data = std::fs::read(path)?;
+    rustls_pemfile::certs(&mut data.as_slice())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| FixgError::InvalidConfig(format!("failed to parse cert file {path:?}: {e}")))
+}
+
+fn load_private_key(path: &PathBuf) -> Result<rustls::pki_types::PrivateKeyDer<'static>> {
+    let data = std::fs::read(path)?;
+    rustls_pemfile::private_key(&mut data.as_slice())
+        .map_err(|e| FixgError::InvalidConfig(format!("failed to parse key file {path:?}: {e}")))?
+        .ok_or_else(|| FixgError::InvalidConfig(format!("no private key found in {path:?}")))
+}
+
+/// Builds the `Transport` implementation selected by `cfg`.
+pub fn make_transport(cfg: &TransportConfig) -> Result<Arc<dyn Transport>> {
+    match cfg {
+        TransportConfig::Tcp => Ok(Arc::new(TcpTransport)),
+        TransportConfig::Tls { .. } => Ok(Arc::new(TlsTransport::from_config(cfg)?)),
+        TransportConfig::WebSocket { .. } => Ok(Arc::new(WebSocketTransport::from_config(cfg)?)),
+        TransportConfig::Memory { .. } => Ok(Arc::new(MemoryTransport::from_config(cfg)?)),
+    }
+}